@@ -0,0 +1,257 @@
+use std::{borrow::Cow, error::Error, fmt::Display, str::FromStr};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Why and where a puzzle input could not be parsed. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `part`, a slice of `line`, which is line `index` of the input (from 0).
+    /// The column is found from where `part` sits inside `line`.
+    pub fn at(index: usize, line: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+        ParseError::new(index + 1, column, part, message)
+    }
+
+    /// An error about the whole of line `index`.
+    pub fn line(index: usize, line: &str, message: impl Into<String>) -> Self {
+        ParseError::at(index, line, line, message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found {:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `part`, a slice of line `index`, reporting its position if it is not a `T`.
+pub fn parse_at<T>(index: usize, line: &str, part: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    part.parse::<T>()
+        .map_err(|e| ParseError::at(index, line, part, e.to_string()))
+}
+
+/// Like `Option::ok_or`, for a piece of line `index` that is missing.
+pub fn expect_part<'a>(
+    index: usize,
+    line: &str,
+    part: Option<&'a str>,
+    what: &str,
+) -> Result<&'a str> {
+    part.ok_or_else(|| {
+        ParseError::new(
+            index + 1,
+            line.chars().count() + 1,
+            line,
+            format!("{} expected", what),
+        )
+    })
+}
+
+/// A day of the calendar: parse the puzzle input once, then answer both parts.
+pub trait Solution: Sized {
+    fn parse(input: &str) -> Result<Self>;
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+
+    /// Parses input as read from a file, whatever its line endings.
+    fn from_input(input: &str) -> Result<Self> {
+        Self::parse(&normalize(input))
+    }
+}
+
+/// Puzzle input the way parsers expect it: no byte order mark, `\n` line endings and no
+/// trailing newline.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let input = input.trim_end_matches(['\r', '\n']);
+    if input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    Error(String),
+}
+
+impl Answer {
+    pub fn is_error(&self) -> bool {
+        matches!(self, Answer::Error(_))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i32, u32, i64, u64, usize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or_else(|| Answer::Error("no answer".to_owned()), Into::into)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Error(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+/// Asserts that a value matches a pattern, with an optional guard and message. A stable
+/// stand-in for `std::assert_matches::assert_matches`.
+#[macro_export]
+macro_rules! assert_matches {
+    ($value:expr, $($pattern:pat_param)|+ $(if $guard:expr)? $(,)?) => {
+        match $value {
+            $($pattern)|+ $(if $guard)? => {}
+            ref value => panic!(
+                "assertion failed: `{:?}` does not match `{}`",
+                value,
+                stringify!($($pattern)|+ $(if $guard)?)
+            ),
+        }
+    };
+    ($value:expr, $($pattern:pat_param)|+ $(if $guard:expr)?, $($message:tt)+) => {
+        match $value {
+            $($pattern)|+ $(if $guard)? => {}
+            ref value => panic!(
+                "assertion failed: `{:?}` does not match `{}`: {}",
+                value,
+                stringify!($($pattern)|+ $(if $guard)?),
+                format_args!($($message)+)
+            ),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(12_u32), Answer::Int(12));
+        assert_eq!(Answer::from(-3_i32), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::Int(u64::MAX as i128));
+        assert_eq!(Answer::from("#.\n.#"), Answer::Text("#.\n.#".to_owned()));
+        assert_eq!(Answer::from(Some(4_usize)), Answer::Int(4));
+        assert!(Answer::from(None::<usize>).is_error());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let line = "forward x5";
+        let error = parse_at::<i32>(2, line, &line[8..]).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 9);
+        assert_eq!(error.text, "x5");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 9: invalid digit found in string (found \"x5\")"
+        );
+        assert_eq!(parse_at::<i32>(0, line, "12"), Ok(12));
+        let error = expect_part(0, "up", None, "an amount").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "an amount expected");
+    }
+
+    #[test]
+    fn test_assert_matches() {
+        assert_matches!(Answer::Int(3), Answer::Int(_));
+        assert_matches!(Answer::Int(3), Answer::Int(n) if n > 2);
+        assert_matches!(Some(1), Some(1) | None, "with {}", "a message");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match `Answer::Text(_)`")]
+    fn test_assert_matches_fails() {
+        assert_matches!(Answer::Int(3), Answer::Text(_));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\n2"), "1\n2");
+        assert_eq!(normalize("1\r\n2\r\n"), "1\n2");
+        assert_eq!(normalize("\u{feff}1\n2\n\n"), "1\n2");
+        assert_eq!(normalize("1\n\n2"), "1\n\n2");
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed("1\n2")));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Int(-5).to_string(), "-5");
+        assert_eq!(Answer::Text("a\nb".to_owned()).to_string(), "a\nb");
+        assert_eq!(Answer::Error("oops".to_owned()).to_string(), "error: oops");
+    }
+}
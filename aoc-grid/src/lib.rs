@@ -0,0 +1,382 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pos {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn offset(&self, (dx, dy): (i32, i32)) -> Self {
+        Self::new(self.x + dx, self.y + dy)
+    }
+}
+
+/// Which cells count as adjacent to a given cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Up, left, right and down.
+    Orthogonal,
+    /// The orthogonal neighbors plus the four diagonals.
+    All,
+}
+
+impl Neighborhood {
+    /// Offsets in reading order, top left to bottom right.
+    pub fn offsets(&self) -> &'static [(i32, i32)] {
+        match self {
+            Neighborhood::Orthogonal => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Neighborhood::All => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut result = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        for row in rows {
+            if result.height == 0 {
+                result.width = row.len() as i32;
+            }
+            assert!(
+                row.len() as i32 == result.width,
+                "row {} should have {} cells",
+                result.height,
+                result.width
+            );
+            result.cells.extend(row);
+            result.height += 1;
+        }
+        result
+    }
+
+    /// One row per line, one cell per character. Surrounding whitespace and blank lines are
+    /// ignored. `parse_cell` says what it expected when a character is not a valid cell.
+    pub fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+        mut parse_cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> aoc_core::Result<Self> {
+        let mut result = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        for (index, line) in lines.into_iter().enumerate() {
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+            let mut width = 0;
+            for (offset, c) in row.char_indices() {
+                let cell = parse_cell(c).map_err(|expected| {
+                    ParseError::at(index, line, &row[offset..offset + c.len_utf8()], expected)
+                })?;
+                result.cells.push(cell);
+                width += 1;
+            }
+            if result.height == 0 {
+                result.width = width;
+            } else if width != result.width {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    format!("rows should have {} cells", result.width),
+                ));
+            }
+            result.height += 1;
+        }
+        Ok(result)
+    }
+
+    pub fn parse_with(
+        text: &str,
+        parse_cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> aoc_core::Result<Self> {
+        Self::parse_lines(text.lines(), parse_cell)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height
+    }
+
+    pub fn pos(&self, x: i32, y: i32) -> Option<Pos> {
+        Some(Pos::new(x, y)).filter(|&pos| self.contains(pos))
+    }
+
+    pub fn pos_at_index(&self, index: usize) -> Option<Pos> {
+        if index < self.cells.len() {
+            Some(Pos::new(
+                index as i32 % self.width,
+                index as i32 / self.width,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Where `pos` is in the cells, in reading order. Panics if it is outside the grid, since
+    /// a column past the width would otherwise land on the next row.
+    pub fn index_of(&self, pos: Pos) -> usize {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        (pos.x + pos.y * self.width) as usize
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(self.index_of(pos))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index_of(pos);
+            self.cells.get_mut(index)
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.cells.len()).filter_map(|index| self.pos_at_index(index))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.cells.iter_mut()
+    }
+
+    pub fn neighbors(
+        &self,
+        pos: Pos,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| pos.offset(offset))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, y: i32) -> impl Iterator<Item = &T> + '_ {
+        (0..self.width).map(move |x| &self[Pos::new(x, y)])
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).map(move |y| &self[Pos::new(x, y)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: i32, height: i32, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; (width * height) as usize],
+        }
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    pub fn parse_digits(text: &str) -> aoc_core::Result<Self> {
+        Self::parse_with(text, digit)
+    }
+}
+
+/// A cell parser for grids of single digits.
+pub fn digit<T: From<u8>>(c: char) -> Result<T, &'static str> {
+    c.to_digit(10)
+        .map(|value| T::from(value as u8))
+        .ok_or("digit expected")
+}
+
+impl Grid<char> {
+    pub fn parse_chars(text: &str) -> aoc_core::Result<Self> {
+        Self::parse_with(text, Ok)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: &str = "123
+456";
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::<u8>::parse_digits(DIGITS).unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.len(), 6);
+        assert_eq!(grid[Pos::new(0, 0)], 1);
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.get(Pos::new(3, 1)), None);
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let grid = Grid::parse_chars("#.\r\n.#\r\n").unwrap();
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::<u8>::parse_digits("123\n45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::<u8>::parse_digits("\n123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.message, "digit expected");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_rows() {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn test_index_past_width() {
+        let grid = Grid::<u8>::parse_digits(DIGITS).unwrap();
+        // Would be the first cell of the second row if the column was not checked.
+        let _ = grid[Pos::new(3, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn test_index_mut_outside() {
+        let mut grid = Grid::<u8>::parse_digits(DIGITS).unwrap();
+        grid[Pos::new(-1, 1)] = 0;
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = Grid::<u8>::parse_digits(DIGITS).unwrap();
+        assert_eq!(grid.positions().count(), 6);
+        assert_eq!(grid.pos_at_index(4), Some(Pos::new(1, 1)));
+        assert_eq!(grid.pos_at_index(6), None);
+        assert_eq!(grid.pos(2, 1), Some(Pos::new(2, 1)));
+        assert_eq!(grid.pos(-1, 1), None);
+        assert_eq!(grid.index_of(Pos::new(1, 1)), 4);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::<u8>::filled(3, 3, 0);
+        let center = Pos::new(1, 1);
+        let corner = Pos::new(0, 0);
+        assert_eq!(grid.neighbors(center, Neighborhood::Orthogonal).count(), 4);
+        assert_eq!(grid.neighbors(center, Neighborhood::All).count(), 8);
+        assert_eq!(grid.neighbors(corner, Neighborhood::Orthogonal).count(), 2);
+        assert_eq!(grid.neighbors(corner, Neighborhood::All).count(), 3);
+        assert_eq!(
+            grid.neighbors(corner, Neighborhood::Orthogonal)
+                .collect::<Vec<Pos>>(),
+            vec![Pos::new(1, 0), Pos::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::<u8>::parse_digits(DIGITS).unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<u8>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<u8>>(), vec![3, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        grid[Pos::new(0, 1)] = 9;
+        assert_eq!(grid.map(|v| v * 2).to_string(), "246\n181012");
+    }
+}
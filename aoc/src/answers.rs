@@ -0,0 +1,208 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{normalize, parse_at, ParseError};
+
+/// FNV-1a, so that hashes stay the same across platforms and compiler versions. The input is
+/// normalized first, so line endings do not change the hash.
+pub fn input_hash(input: &str) -> u64 {
+    normalize(input)
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// A known answer for one part of a day, on the input at `input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub hash: u64,
+    /// Relative paths start from the workspace directory.
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+impl Entry {
+    fn same_key(&self, other: &Entry) -> bool {
+        self.day == other.day && self.part == other.part && self.hash == other.hash
+    }
+}
+
+/// The answers file: one entry per line, `DAY PART HASH INPUT ANSWER`, `#` starts a comment.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn parse(text: &str) -> aoc_core::Result<Self> {
+        let mut entries = vec![];
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(5, ' ');
+            let mut field = |what: &str| {
+                fields
+                    .next()
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| {
+                        let column = line.chars().count() + 1;
+                        ParseError::new(index + 1, column, line, format!("{} expected", what))
+                    })
+            };
+            let day = parse_at(index, line, field("a day")?)?;
+            let part = parse_at(index, line, field("a part")?)?;
+            let hash = field("an input hash")?;
+            let hash = u64::from_str_radix(hash, 16)
+                .map_err(|e| ParseError::at(index, line, hash, e.to_string()))?;
+            let input = PathBuf::from(field("an input path")?);
+            let answer = unescape(field("an answer")?);
+            entries.push(Entry {
+                day,
+                part,
+                hash,
+                input,
+                answer,
+            });
+        }
+        Ok(Registry { entries })
+    }
+
+    /// An absent file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Ok(Registry::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Registry::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> + '_ {
+        self.entries.iter()
+    }
+
+    /// Adds `entry`, replacing any entry for the same day, part and input hash.
+    pub fn insert(&mut self, entry: Entry) {
+        match self.entries.iter_mut().find(|e| e.same_key(&entry)) {
+            Some(existing) => *existing = entry,
+            None => {
+                self.entries.push(entry);
+                self.entries.sort_by_key(|e| (e.day, e.part));
+            }
+        }
+    }
+}
+
+impl Display for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part input-hash input answer")?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:02} {} {:016x} {} {}",
+                entry.day,
+                entry.part,
+                entry.hash,
+                entry.input.display(),
+                escape(&entry.answer)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Multi-line answers are stored on one line.
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(input_hash("199\n200"), input_hash("199\n201"));
+        assert_eq!(input_hash("199\r\n200\r\n"), input_hash("199\n200"));
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::default();
+        registry.insert(Entry {
+            day: 13,
+            part: 2,
+            hash: 0xabc,
+            input: PathBuf::from("day13/input.txt"),
+            answer: "#.\\\n.#".to_owned(),
+        });
+        registry.insert(Entry {
+            day: 1,
+            part: 1,
+            hash: 0x12,
+            input: PathBuf::from("day01/input.txt"),
+            answer: "1616".to_owned(),
+        });
+        let text = registry.to_string();
+        assert_eq!(
+            text,
+            "# day part input-hash input answer
+01 1 0000000000000012 day01/input.txt 1616
+13 2 0000000000000abc day13/input.txt #.\\\\\\n.#
+"
+        );
+        assert_eq!(Registry::parse(&text).unwrap(), registry);
+        registry.insert(Entry {
+            day: 1,
+            part: 1,
+            hash: 0x12,
+            input: PathBuf::from("day01/input.txt"),
+            answer: "1617".to_owned(),
+        });
+        assert_eq!(registry.entries().count(), 2);
+        assert_eq!(registry.entries().next().unwrap().answer, "1617");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Registry::parse("01 1 zz day01/input.txt 1616").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Registry::parse("# header\n01 1 12 day01/input.txt").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "an answer expected");
+    }
+}
@@ -0,0 +1,243 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{parse_at, ParseError, Solution};
+
+/// Times the phases of one day on one input.
+pub type BenchFn = fn(&str, &Budget) -> Result<Timings, String>;
+
+/// How long each phase is sampled for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Budget {
+    pub min_samples: usize,
+    pub max_samples: usize,
+    pub time: Duration,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Budget {
+            min_samples: 5,
+            max_samples: 1000,
+            time: Duration::from_millis(200),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        Phase::ALL.into_iter().find(|phase| phase.name() == name)
+    }
+}
+
+/// Median durations, indexed by `Phase as usize`.
+pub type Timings = [Duration; 3];
+
+fn median_time<T>(budget: &Budget, mut f: impl FnMut() -> T) -> Duration {
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < budget.min_samples.max(1)
+        || (samples.len() < budget.max_samples && start.elapsed() < budget.time)
+    {
+        let sample_start = Instant::now();
+        black_box(f());
+        samples.push(sample_start.elapsed());
+    }
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+pub fn time_phases<S: Solution>(input: &str, budget: &Budget) -> Result<Timings, String> {
+    let solution = S::from_input(input).map_err(|e| e.to_string())?;
+    Ok([
+        median_time(budget, || S::from_input(black_box(input))),
+        median_time(budget, || solution.part1()),
+        median_time(budget, || solution.part2()),
+    ])
+}
+
+/// Which timing a baseline entry is for: day, input name and phase.
+pub type Key = (u8, String, Phase);
+
+/// Saved timings, one per line: `DAY INPUT PHASE NANOSECONDS`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    times: BTreeMap<Key, Duration>,
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> aoc_core::Result<Self> {
+        let mut times = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 4 {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    "DAY INPUT PHASE NANOSECONDS expected",
+                ));
+            }
+            let phase = Phase::from_name(fields[2]).ok_or_else(|| {
+                ParseError::at(index, line, fields[2], "parse, part1 or part2 expected")
+            })?;
+            let key = (
+                parse_at(index, line, fields[0])?,
+                fields[1].to_owned(),
+                phase,
+            );
+            let nanos = parse_at(index, line, fields[3])?;
+            times.insert(key, Duration::from_nanos(nanos));
+        }
+        Ok(Baseline { times })
+    }
+
+    /// An absent file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Ok(Baseline::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn get(&self, key: &Key) -> Option<Duration> {
+        self.times.get(key).copied()
+    }
+
+    pub fn insert(&mut self, key: Key, time: Duration) {
+        self.times.insert(key, time);
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day input phase nanoseconds")?;
+        for ((day, input, phase), time) in &self.times {
+            writeln!(
+                f,
+                "{:02} {} {} {}",
+                day,
+                input,
+                phase.name(),
+                time.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Below this, differences are timer noise rather than regressions.
+const NOISE: Duration = Duration::from_micros(5);
+
+/// The relative change from `baseline` to `current`, when it is slower by more than `threshold`.
+pub fn regression(current: Duration, baseline: Duration, threshold: f64) -> Option<f64> {
+    let change = current.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0;
+    (current > baseline + NOISE && change > threshold).then_some(change)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline() {
+        let mut baseline = Baseline::default();
+        baseline.insert(
+            (7, "input".to_owned(), Phase::Part2),
+            Duration::from_millis(340),
+        );
+        baseline.insert(
+            (1, "sample".to_owned(), Phase::Parse),
+            Duration::from_nanos(812),
+        );
+        let text = baseline.to_string();
+        assert_eq!(
+            text,
+            "# day input phase nanoseconds
+01 sample parse 812
+07 input part2 340000000
+"
+        );
+        assert_eq!(Baseline::parse(&text).unwrap(), baseline);
+        let error = Baseline::parse("01 sample part3 812").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert!(Baseline::parse("01 sample parse").is_err());
+    }
+
+    #[test]
+    fn test_regression() {
+        let ms = Duration::from_millis;
+        assert_eq!(regression(ms(100), ms(100), 0.1), None);
+        assert_eq!(regression(ms(105), ms(100), 0.1), None);
+        let change = regression(ms(150), ms(100), 0.1).unwrap();
+        assert!((change - 0.5).abs() < 1e-9);
+        let ns = Duration::from_nanos;
+        assert_eq!(regression(ns(900), ns(300), 0.1), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(812)), "812 ns");
+        assert_eq!(format_duration(Duration::from_nanos(35_240)), "35.2 µs");
+        assert_eq!(format_duration(Duration::from_millis(340)), "340.0 ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50 s");
+    }
+
+    #[test]
+    fn test_median_time() {
+        let budget = Budget {
+            min_samples: 3,
+            max_samples: 3,
+            time: Duration::ZERO,
+        };
+        let mut calls = 0;
+        median_time(&budget, || calls += 1);
+        assert_eq!(calls, 3);
+    }
+}
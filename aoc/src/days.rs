@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Answer, Solution};
+
+use crate::bench::{self, BenchFn};
+
+pub type PartFn = fn(&str) -> Answer;
+
+pub struct Day {
+    pub number: u8,
+    pub part1: PartFn,
+    pub part2: PartFn,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<PartFn> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    /// The example from the puzzle text.
+    pub fn sample_path(&self) -> PathBuf {
+        self.dir().join("sample.txt")
+    }
+
+    fn dir(&self) -> PathBuf {
+        workspace_dir().join(format!("day{:02}", self.number))
+    }
+}
+
+/// The directory holding the day crates and the answers file.
+pub fn workspace_dir() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf()
+}
+
+fn solve<S: Solution>(input: &str, part: fn(&S) -> Answer) -> Answer {
+    match S::from_input(input) {
+        Ok(solution) => part(&solution),
+        Err(e) => Answer::Error(e.to_string()),
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $solution:ty) => {
+        Day {
+            number: $number,
+            part1: |input| solve::<$solution>(input, <$solution as Solution>::part1),
+            part2: |input| solve::<$solution>(input, <$solution as Solution>::part2),
+            bench: bench::time_phases::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: [Day; 18] = [
+    day!(1, day01::Sonar),
+    day!(2, day02::Course),
+    day!(3, day03::Values),
+    day!(4, day04::Bingo),
+    day!(5, day05::Vents),
+    day!(6, day06::School),
+    day!(7, day07::Crabs),
+    day!(8, day08::Notes),
+    day!(9, day09::HeightMap),
+    day!(10, day10::Subsystem),
+    day!(11, day11::Grid),
+    day!(12, day12::Graph),
+    day!(13, day13::Problem),
+    day!(14, day14::Prob),
+    day!(15, day15::Board),
+    day!(16, day16::Transmission),
+    day!(17, day17::Target),
+    day!(18, day18::Homework),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,111 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::days::Day;
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `input.txt`.
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, Box<dyn Error>> {
+        match self {
+            InputSource::Default => read_file(&day.input_path()),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut result = String::new();
+                io::stdin().read_to_string(&mut result)?;
+                Ok(result)
+            }
+            InputSource::Inline(text) => Ok(text.clone()),
+        }
+    }
+
+    /// The input one read at a time, so that stdin can be followed while it is written.
+    pub fn reader(&self, day: &Day) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+        let open = |path: &Path| File::open(path).map_err(|e| format!("{}: {}", path.display(), e));
+        Ok(match self {
+            InputSource::Default => Box::new(BufReader::new(open(&day.input_path())?)),
+            InputSource::File(path) => Box::new(BufReader::new(open(path)?)),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Inline(text) => Box::new(Cursor::new(text.clone().into_bytes())),
+        })
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, Box<dyn Error>> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("day17/input.txt"),
+            InputSource::File(PathBuf::from("day17/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let day = days::find(17).unwrap();
+        let inline = InputSource::Inline("target area: x=20..30, y=-10..-5".to_owned());
+        assert_eq!(
+            inline.read(day).unwrap(),
+            "target area: x=20..30, y=-10..-5"
+        );
+        assert!(InputSource::Default
+            .read(day)
+            .unwrap()
+            .starts_with("target area"));
+        assert!(InputSource::from_arg("no/such/file.txt").read(day).is_err());
+    }
+
+    #[test]
+    fn test_reader() {
+        let day = days::find(1).unwrap();
+        let inline = InputSource::Inline("199\n200\n".to_owned());
+        let lines: Vec<String> = inline
+            .reader(day)
+            .unwrap()
+            .lines()
+            .map_while(Result::ok)
+            .collect();
+        assert_eq!(lines, ["199", "200"]);
+        let mut text = String::new();
+        InputSource::Default
+            .reader(day)
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, InputSource::Default.read(day).unwrap());
+        assert!(InputSource::from_arg("no/such/file.txt")
+            .reader(day)
+            .is_err());
+    }
+}
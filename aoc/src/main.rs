@@ -0,0 +1,875 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+mod answers;
+mod bench;
+mod days;
+mod input;
+
+use answers::{input_hash, Entry, Registry};
+use bench::{format_duration, regression, Baseline, Budget, Phase};
+use days::Day;
+use input::InputSource;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+const USAGE: &str = "usage:
+  aoc run [DAY] [--part 1|2] [--input PATH|-] [--text INPUT]
+  aoc record [DAY] [--part 1|2] [--input PATH]
+  aoc verify [DAY] [--part 1|2]
+  aoc bench [DAY] [--time MS] [--threshold PERCENT] [--baseline PATH] [--save]
+  aoc sonar [--input PATH|-] [--threshold DEPTH | --z-score Z] [--json] [--lenient]
+  aoc sonar --follow [--input PATH|-] [--lenient]
+  aoc course [--input PATH|-] [--model NAME]... [--csv PATH] [--svg PATH]
+  aoc plan [--model plain|aim] [--max-amount N] [--max-depth-step N] [--max-moves N] HORIZONTAL DEPTH
+  aoc bingo [--input PATH|-] [--json] [--trials N] [--seed N]
+  aoc bingo --generate BOARDS [--seed N]";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run(RunArgs::parse(args)?),
+        Some("record") => record(RunArgs::parse(args)?),
+        Some("verify") => verify(RunArgs::parse(args)?),
+        Some("bench") => bench(BenchArgs::parse(args)?),
+        Some("sonar") => sonar(SonarArgs::parse(args)?),
+        Some("course") => course(CourseArgs::parse(args)?),
+        Some("plan") => plan(PlanArgs::parse(args)?),
+        Some("bingo") => bingo(BingoArgs::parse(args)?),
+        _ => Err(USAGE.into()),
+    }
+}
+
+#[derive(Debug, Default)]
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: InputSource,
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = RunArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let part = args.next().ok_or("--part expects a value")?;
+                    result.part = Some(part.parse()?);
+                }
+                "--input" | "-i" => {
+                    let path = args
+                        .next()
+                        .ok_or("--input expects a path, or - for stdin")?;
+                    result.input = InputSource::from_arg(&path);
+                }
+                "--text" | "-t" => {
+                    let text = args.next().ok_or("--text expects the puzzle input")?;
+                    result.input = InputSource::Inline(text);
+                }
+                day => result.day = Some(day.parse()?),
+            }
+        }
+        Ok(result)
+    }
+
+    fn days(&self) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+        match self.day {
+            Some(number) => {
+                Ok(vec![days::find(number).ok_or_else(|| {
+                    format!("no solution for day {}", number)
+                })?])
+            }
+            None if self.input == InputSource::Default => Ok(days::DAYS.iter().collect()),
+            None => Err("a custom input needs a DAY".into()),
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    fn selects(&self, entry: &Entry) -> bool {
+        self.day.is_none_or(|day| day == entry.day)
+            && self.part.is_none_or(|part| part == entry.part)
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    for day in args.days()? {
+        let input = args.input.read(day)?;
+        for part in args.parts() {
+            let solve = day.part(part).ok_or_else(|| format!("no part {}", part))?;
+            let answer = solve(&input).to_string();
+            if answer.contains('\n') {
+                println!("Day {:02} part {} :\n{}", day.number, part, answer);
+            } else {
+                println!("Day {:02} part {} : {}", day.number, part, answer);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn answers_path() -> PathBuf {
+    days::workspace_dir().join("answers.txt")
+}
+
+/// How an input path is written in the answers file: relative to the workspace when inside it.
+fn registered_path(path: &Path) -> PathBuf {
+    let root = days::workspace_dir();
+    let root = fs::canonicalize(&root).unwrap_or(root);
+    let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    path.strip_prefix(&root)
+        .map(Path::to_path_buf)
+        .unwrap_or(path)
+}
+
+/// Stores the current answers for the given input, replacing older answers for that same input.
+fn record(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    let path = answers_path();
+    let mut registry = Registry::load(&path)?;
+    for day in args.days()? {
+        let input_path = match &args.input {
+            InputSource::Default => day.input_path(),
+            InputSource::File(path) => path.clone(),
+            _ => return Err("answers can only be recorded for input files".into()),
+        };
+        let input_path = registered_path(&input_path);
+        if input_path.to_string_lossy().contains(char::is_whitespace) {
+            return Err(format!(
+                "{}: input paths cannot contain spaces",
+                input_path.display()
+            )
+            .into());
+        }
+        let input = args.input.read(day)?;
+        for part in args.parts() {
+            let solve = day.part(part).ok_or_else(|| format!("no part {}", part))?;
+            let answer = solve(&input);
+            if answer.is_error() {
+                return Err(format!("day {} part {}: {}", day.number, part, answer).into());
+            }
+            registry.insert(Entry {
+                day: day.number,
+                part,
+                hash: input_hash(&input),
+                input: input_path.clone(),
+                answer: answer.to_string(),
+            });
+            println!("Day {:02} part {} : {}", day.number, part, answer);
+        }
+    }
+    registry.save(&path)
+}
+
+/// Checks every registered answer against what the solutions give today.
+fn verify(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    let registry = Registry::load(&answers_path())?;
+    let (mut checked, mut failed) = (0, 0);
+    for entry in registry.entries().filter(|entry| args.selects(entry)) {
+        let day =
+            days::find(entry.day).ok_or_else(|| format!("no solution for day {}", entry.day))?;
+        let solve = day
+            .part(entry.part)
+            .ok_or_else(|| format!("no part {}", entry.part))?;
+        let input = InputSource::File(days::workspace_dir().join(&entry.input)).read(day);
+        let status = match input {
+            Err(e) => Some(e.to_string()),
+            Ok(input) if input_hash(&input) != entry.hash => Some(format!(
+                "{} has changed since it was recorded",
+                entry.input.display()
+            )),
+            Ok(input) => {
+                let answer = solve(&input).to_string();
+                (answer != entry.answer)
+                    .then(|| format!("expected {:?}, got {:?}", entry.answer, answer))
+            }
+        };
+        checked += 1;
+        match status {
+            None => println!("Day {:02} part {} : ok", entry.day, entry.part),
+            Some(message) => {
+                failed += 1;
+                println!(
+                    "Day {:02} part {} : FAILED {}",
+                    entry.day, entry.part, message
+                );
+            }
+        }
+    }
+    println!("{} checked, {} failed", checked, failed);
+    if failed > 0 {
+        Err(format!("{} of {} answers failed", failed, checked).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    day: Option<u8>,
+    budget: Budget,
+    /// Slowdown, as a fraction of the baseline, above which a phase is flagged.
+    threshold: f64,
+    baseline: PathBuf,
+    save: bool,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            day: None,
+            budget: Budget::default(),
+            threshold: 0.1,
+            baseline: days::workspace_dir().join("bench-baseline.txt"),
+            save: false,
+        }
+    }
+}
+
+impl BenchArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = BenchArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--time" => {
+                    let millis = args.next().ok_or("--time expects milliseconds")?;
+                    result.budget.time = Duration::from_millis(millis.parse()?);
+                }
+                "--threshold" => {
+                    let percent: f64 = args
+                        .next()
+                        .ok_or("--threshold expects a percentage")?
+                        .parse()?;
+                    result.threshold = percent / 100.0;
+                }
+                "--baseline" => {
+                    result.baseline =
+                        PathBuf::from(args.next().ok_or("--baseline expects a path")?);
+                }
+                "--save" => result.save = true,
+                day => result.day = Some(day.parse()?),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Times parse, part 1 and part 2 of each day on its sample and its input, against a baseline.
+fn bench(args: BenchArgs) -> Result<(), Box<dyn Error>> {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => {
+            vec![days::find(number).ok_or_else(|| format!("no solution for day {}", number))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+    let mut baseline = Baseline::load(&args.baseline)?;
+    let mut regressions = 0;
+    for day in days {
+        for (name, path) in [("sample", day.sample_path()), ("input", day.input_path())] {
+            let Ok(input) = fs::read_to_string(&path) else {
+                println!(
+                    "Day {:02} {:6} : skipped, no {}",
+                    day.number,
+                    name,
+                    path.display()
+                );
+                continue;
+            };
+            let timings = match (day.bench)(&input, &args.budget) {
+                Ok(timings) => timings,
+                Err(e) => {
+                    println!("Day {:02} {:6} : error: {}", day.number, name, e);
+                    continue;
+                }
+            };
+            for phase in Phase::ALL {
+                let key = (day.number, name.to_owned(), phase);
+                let time = timings[phase as usize];
+                let mut line = format!(
+                    "Day {:02} {:6} {:5} : {:>10}",
+                    day.number,
+                    name,
+                    phase.name(),
+                    format_duration(time)
+                );
+                if let Some(before) = baseline.get(&key) {
+                    line += &format!("  (baseline {})", format_duration(before));
+                    if let Some(change) = regression(time, before, args.threshold) {
+                        regressions += 1;
+                        line += &format!("  REGRESSION +{:.0}%", change * 100.0);
+                    }
+                }
+                println!("{}", line);
+                if args.save {
+                    baseline.insert(key, time);
+                }
+            }
+        }
+    }
+    if args.save {
+        baseline.save(&args.baseline)?;
+        println!("baseline saved to {}", args.baseline.display());
+    }
+    if regressions > 0 {
+        Err(format!("{} phases slower than the baseline", regressions).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct SonarArgs {
+    input: InputSource,
+    rule: day01::JumpRule,
+    json: bool,
+    mode: day01::Mode,
+    /// Print the running count of increases as each depth comes in, instead of a report.
+    follow: bool,
+}
+
+impl Default for SonarArgs {
+    fn default() -> Self {
+        SonarArgs {
+            input: InputSource::Default,
+            rule: day01::JumpRule::ZScore(3.0),
+            json: false,
+            mode: day01::Mode::Strict,
+            follow: false,
+        }
+    }
+}
+
+impl SonarArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = SonarArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args
+                        .next()
+                        .ok_or("--input expects a path, or - for stdin")?;
+                    result.input = InputSource::from_arg(&path);
+                }
+                "--threshold" => {
+                    let depth = args.next().ok_or("--threshold expects a depth change")?;
+                    result.rule = day01::JumpRule::Threshold(depth.parse()?);
+                }
+                "--z-score" => {
+                    let z = args.next().ok_or("--z-score expects a number")?;
+                    result.rule = day01::JumpRule::ZScore(z.parse()?);
+                }
+                "--json" => result.json = true,
+                "--lenient" => result.mode = day01::Mode::Lenient,
+                "--follow" | "-f" => result.follow = true,
+                arg => return Err(format!("unexpected argument {:?}", arg).into()),
+            }
+        }
+        if result.follow && result.json {
+            return Err("--follow prints counts as they come, not a report".into());
+        }
+        Ok(result)
+    }
+}
+
+/// Summarizes day 1 depths, for people watching the sonar rather than solving the puzzle.
+/// In lenient mode, lines that are not depths are reported on stderr and skipped.
+fn sonar(args: SonarArgs) -> Result<(), Box<dyn Error>> {
+    let day = days::find(1).ok_or("no solution for day 1")?;
+    if args.follow {
+        return follow_sonar(args.input.reader(day)?, args.mode, io::stdout().lock());
+    }
+    let input = args.input.read(day)?;
+    let (depths, warnings) = day01::parse_with(&aoc_core::normalize(&input), args.mode)?;
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+    let report = day01::Sonar::new(depths).report(args.rule);
+    if args.json {
+        println!("{}", report.to_json());
+    } else {
+        println!("{}", report);
+    }
+    Ok(())
+}
+
+/// Writes how many depths so far were deeper than the one before, after every depth read,
+/// without waiting for the end of the input.
+fn follow_sonar(
+    reader: impl BufRead,
+    mode: day01::Mode,
+    mut out: impl Write,
+) -> Result<(), Box<dyn Error>> {
+    let mut error = None;
+    let depths = day01::read_depths(reader)
+        .map_while(|depth| match (depth, mode) {
+            (Ok(depth), _) => Some(Some(depth)),
+            (Err(e), day01::Mode::Lenient) => {
+                eprintln!("warning: {}", e);
+                Some(None)
+            }
+            (Err(e), day01::Mode::Strict) => {
+                error = Some(e);
+                None
+            }
+        })
+        .flatten();
+    for count in day01::running_increase_count(depths) {
+        writeln!(out, "{}", count)?;
+    }
+    match error {
+        Some(e) => Err(e.into()),
+        None => Ok(()),
+    }
+}
+
+#[derive(Debug, Default)]
+struct CourseArgs {
+    input: InputSource,
+    /// Every registered model when empty.
+    models: Vec<String>,
+    /// Where to write the trace of every move, if anywhere.
+    csv: Option<PathBuf>,
+    svg: Option<PathBuf>,
+}
+
+impl CourseArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = CourseArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args
+                        .next()
+                        .ok_or("--input expects a path, or - for stdin")?;
+                    result.input = InputSource::from_arg(&path);
+                }
+                "--model" | "-m" => {
+                    result
+                        .models
+                        .push(args.next().ok_or("--model expects a model name")?);
+                }
+                "--csv" => {
+                    result.csv = Some(PathBuf::from(args.next().ok_or("--csv expects a path")?));
+                }
+                "--svg" => {
+                    result.svg = Some(PathBuf::from(args.next().ok_or("--svg expects a path")?));
+                }
+                arg => return Err(format!("unexpected argument {:?}", arg).into()),
+            }
+        }
+        Ok(result)
+    }
+
+    fn models(&self) -> Result<Vec<Box<dyn day02::SubmarineModel>>, Box<dyn Error>> {
+        if self.models.is_empty() {
+            return Ok(day02::models());
+        }
+        self.models
+            .iter()
+            .map(|name| {
+                day02::find_model(name).ok_or_else(|| {
+                    let names: Vec<String> =
+                        day02::models().iter().map(|model| model.name()).collect();
+                    format!("no model {:?}, try one of {}", name, names.join(", ")).into()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Runs a day 2 course script under several submarine models, side by side.
+fn course(args: CourseArgs) -> Result<(), Box<dyn Error>> {
+    let day = days::find(2).ok_or("no solution for day 2")?;
+    let models = args.models()?;
+    let input = args.input.read(day)?;
+    let script = day02::Script::parse(&aoc_core::normalize(&input))?;
+    let states = day02::run_models(script.moves(), &models);
+    if args.csv.is_some() || args.svg.is_some() {
+        let trace = day02::Trace::record(script.moves(), &models)?;
+        for (path, text) in [(&args.csv, trace.to_csv()), (&args.svg, trace.to_svg())] {
+            if let Some(path) = path {
+                fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))?;
+            }
+        }
+    }
+    println!(
+        "{:16} {:>12} {:>12} {:>12} {:>16}",
+        "model", "horizontal", "depth", "aim", "product"
+    );
+    for (model, state) in models.iter().zip(states) {
+        let Some(state) = state else {
+            println!("{:16} overflow", model.name());
+            continue;
+        };
+        let product = state
+            .product()
+            .map_or_else(|| "overflow".to_owned(), |product| product.to_string());
+        println!(
+            "{:16} {:>12} {:>12} {:>12} {:>16}",
+            model.name(),
+            state.horizontal,
+            state.depth,
+            state.aim,
+            product
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct PlanArgs {
+    /// The aim model unless told otherwise.
+    plain: bool,
+    bounds: day02::Bounds,
+    target: Vec<i64>,
+}
+
+impl PlanArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = PlanArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--model" | "-m" => match args.next().as_deref() {
+                    Some("plain") => result.plain = true,
+                    Some("aim") => result.plain = false,
+                    _ => return Err("--model expects plain or aim".into()),
+                },
+                "--max-amount" => {
+                    let amount = args.next().ok_or("--max-amount expects an amount")?;
+                    result.bounds.max_amount = amount.parse()?;
+                }
+                "--max-depth-step" => {
+                    let step = args
+                        .next()
+                        .ok_or("--max-depth-step expects a depth change")?;
+                    result.bounds.max_depth_step = Some(step.parse()?);
+                }
+                "--max-moves" => {
+                    let moves = args.next().ok_or("--max-moves expects a number of moves")?;
+                    result.bounds.max_moves = moves.parse()?;
+                }
+                value => result.target.push(value.parse()?),
+            }
+        }
+        if result.target.len() != 2 {
+            return Err("plan expects a horizontal position and a depth".into());
+        }
+        Ok(result)
+    }
+}
+
+/// Prints the shortest day 2 course to a position, one move per line, ready to be used as input.
+fn plan(args: PlanArgs) -> Result<(), Box<dyn Error>> {
+    use day02::Plan;
+    let model: &dyn Plan = if args.plain {
+        &day02::Plain
+    } else {
+        &day02::Aim
+    };
+    let (horizontal, depth) = (args.target[0], args.target[1]);
+    let moves = model
+        .plan(horizontal, depth, &args.bounds)
+        .ok_or_else(|| format!("no plan within {} moves", args.bounds.max_moves))?;
+    for m in moves {
+        println!("{}", m);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct BingoArgs {
+    input: InputSource,
+    json: bool,
+    /// Simulate this many games with the numbers shuffled, instead of playing the game once.
+    trials: Option<usize>,
+    seed: u64,
+    /// Print a random input with this many boards instead.
+    generate: Option<usize>,
+}
+
+impl BingoArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut result = BingoArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args
+                        .next()
+                        .ok_or("--input expects a path, or - for stdin")?;
+                    result.input = InputSource::from_arg(&path);
+                }
+                "--json" => result.json = true,
+                "--trials" => {
+                    let trials = args.next().ok_or("--trials expects a number of games")?;
+                    result.trials = Some(trials.parse()?);
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed expects a number")?;
+                    result.seed = seed.parse()?;
+                }
+                "--generate" => {
+                    let boards = args.next().ok_or("--generate expects a number of boards")?;
+                    result.generate = Some(boards.parse()?);
+                }
+                arg => return Err(format!("unexpected argument {:?}", arg).into()),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Plays a whole day 4 game and prints every draw and every win, in order, with each winning
+/// board as it stands when it wins. With `--trials`, prints the odds of every board over that
+/// many shuffled games instead.
+fn bingo(args: BingoArgs) -> Result<(), Box<dyn Error>> {
+    if let Some(boards) = args.generate {
+        let shape = day04::Shape {
+            boards,
+            ..day04::Shape::default()
+        };
+        print!(
+            "{}",
+            day04::random_input(&shape, &mut ChaCha8Rng::seed_from_u64(args.seed))
+        );
+        return Ok(());
+    }
+    let day = days::find(4).ok_or("no solution for day 4")?;
+    let input = args.input.read(day)?;
+    let input = aoc_core::normalize(&input);
+    let lines: Vec<&str> = input.lines().collect();
+    let mut game = day04::Game::parse(&lines)?;
+    match (args.trials, args.json) {
+        (Some(trials), true) => println!("{}", game.simulate(trials, args.seed).to_json()),
+        (Some(trials), false) => println!("{}", game.simulate(trials, args.seed)),
+        (None, true) => println!("{}", game.log().to_json()),
+        (None, false) => {
+            while let Some(events) = game.draw() {
+                for event in events {
+                    println!("{}", event);
+                    if let day04::Event::Win { board, .. } = event {
+                        println!("{}", game.render(board));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> impl Iterator<Item = String> {
+        list.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn test_run_args() {
+        let parsed = RunArgs::parse(args(&["15", "--part", "2"])).unwrap();
+        assert_eq!(parsed.day, Some(15));
+        assert_eq!(parsed.part, Some(2));
+        let parsed = RunArgs::parse(args(&[])).unwrap();
+        assert_eq!(parsed.day, None);
+        assert_eq!(parsed.part, None);
+        assert_eq!(parsed.input, InputSource::Default);
+        let parsed = RunArgs::parse(args(&["17", "-i", "-"])).unwrap();
+        assert_eq!(parsed.input, InputSource::Stdin);
+        let parsed = RunArgs::parse(args(&["17", "--text", "target area: x=20..30, y=-10..-5"]));
+        assert_eq!(
+            parsed.unwrap().input,
+            InputSource::Inline("target area: x=20..30, y=-10..-5".to_owned())
+        );
+        assert!(RunArgs::parse(args(&["--part"])).is_err());
+        assert!(RunArgs::parse(args(&["--input"])).is_err());
+        assert!(RunArgs::parse(args(&["fifteen"])).is_err());
+    }
+
+    #[test]
+    fn test_selects() {
+        let entry = Entry {
+            day: 6,
+            part: 2,
+            hash: 0,
+            input: PathBuf::from("day06/input.txt"),
+            answer: "26984457539".to_owned(),
+        };
+        assert!(RunArgs::parse(args(&[])).unwrap().selects(&entry));
+        assert!(RunArgs::parse(args(&["6"])).unwrap().selects(&entry));
+        assert!(!RunArgs::parse(args(&["6", "-p", "1"]))
+            .unwrap()
+            .selects(&entry));
+        assert_eq!(
+            registered_path(&days::find(6).unwrap().input_path()),
+            PathBuf::from("day06/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_bench_args() {
+        let parsed = BenchArgs::parse(args(&["7", "--threshold", "25", "--time", "50", "--save"]));
+        let parsed = parsed.unwrap();
+        assert_eq!(parsed.day, Some(7));
+        assert_eq!(parsed.threshold, 0.25);
+        assert_eq!(parsed.budget.time, Duration::from_millis(50));
+        assert!(parsed.save);
+        let parsed = BenchArgs::parse(args(&["--baseline", "/tmp/base.txt"])).unwrap();
+        assert_eq!(parsed.baseline, PathBuf::from("/tmp/base.txt"));
+        assert!(!parsed.save);
+        assert!(BenchArgs::parse(args(&["--threshold", "ten"])).is_err());
+    }
+
+    #[test]
+    fn test_sonar_args() {
+        let parsed = SonarArgs::parse(args(&["--threshold", "20", "--json", "-i", "-"])).unwrap();
+        assert_eq!(parsed.rule, day01::JumpRule::Threshold(20));
+        assert_eq!(parsed.input, InputSource::Stdin);
+        assert!(parsed.json);
+        let parsed = SonarArgs::parse(args(&["--z-score", "2.5"])).unwrap();
+        assert_eq!(parsed.rule, day01::JumpRule::ZScore(2.5));
+        assert!(!parsed.json);
+        assert_eq!(parsed.mode, day01::Mode::Strict);
+        let parsed = SonarArgs::parse(args(&["--lenient"])).unwrap();
+        assert_eq!(parsed.mode, day01::Mode::Lenient);
+        assert!(!parsed.follow);
+        let parsed = SonarArgs::parse(args(&["--follow", "-i", "-"])).unwrap();
+        assert!(parsed.follow);
+        assert!(SonarArgs::parse(args(&["--follow", "--json"])).is_err());
+        assert!(SonarArgs::parse(args(&["--threshold", "deep"])).is_err());
+        assert!(SonarArgs::parse(args(&["1"])).is_err());
+    }
+
+    #[test]
+    fn test_follow_sonar() {
+        let follow = |text: &str, mode| {
+            let mut out = vec![];
+            let result = follow_sonar(text.as_bytes(), mode, &mut out);
+            (
+                String::from_utf8(out).unwrap(),
+                result.map_err(|e| e.to_string()),
+            )
+        };
+        let (out, result) = follow("199\n200\n208\n210\n200\n207\n", day01::Mode::Strict);
+        assert_eq!(out, "0\n1\n2\n3\n3\n4\n");
+        assert!(result.is_ok());
+        // Counts already read are printed before the bad line stops a strict run.
+        let (out, result) = follow("1\n2\nthree\n4\n", day01::Mode::Strict);
+        assert_eq!(out, "0\n1\n");
+        assert!(result.unwrap_err().contains("line 3"));
+        let (out, result) = follow("1\n2\nthree\n4\n", day01::Mode::Lenient);
+        assert_eq!(out, "0\n1\n2\n");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_course_args() {
+        let parsed = CourseArgs::parse(args(&["-m", "aim", "--model", "plain"])).unwrap();
+        assert_eq!(parsed.models, ["aim", "plain"]);
+        let names: Vec<String> = parsed.models().unwrap().iter().map(|m| m.name()).collect();
+        assert_eq!(names, ["aim", "plain"]);
+        let parsed = CourseArgs::parse(args(&[])).unwrap();
+        assert_eq!(parsed.models().unwrap().len(), day02::models().len());
+        let parsed = CourseArgs::parse(args(&["--model", "sideways"])).unwrap();
+        assert!(parsed.models().is_err());
+        assert!(CourseArgs::parse(args(&["--model"])).is_err());
+        let parsed = CourseArgs::parse(args(&["--svg", "/tmp/course.svg"])).unwrap();
+        assert_eq!(parsed.svg, Some(PathBuf::from("/tmp/course.svg")));
+        assert_eq!(parsed.csv, None);
+    }
+
+    #[test]
+    fn test_plan_args() {
+        let parsed = PlanArgs::parse(args(&["15", "-60", "--max-moves", "8"])).unwrap();
+        assert_eq!(parsed.target, [15, -60]);
+        assert!(!parsed.plain);
+        assert_eq!(parsed.bounds.max_moves, 8);
+        let parsed =
+            PlanArgs::parse(args(&["-m", "plain", "--max-depth-step", "3", "1", "2"])).unwrap();
+        assert!(parsed.plain);
+        assert_eq!(parsed.bounds.max_depth_step, Some(3));
+        assert!(PlanArgs::parse(args(&["15"])).is_err());
+        assert!(PlanArgs::parse(args(&["--model", "drag-20", "1", "2"])).is_err());
+        assert!(PlanArgs::parse(args(&["--max-amount", "x", "1", "2"])).is_err());
+    }
+
+    #[test]
+    fn test_bingo_args() {
+        let parsed = BingoArgs::parse(args(&["--json", "-i", "-"])).unwrap();
+        assert_eq!(parsed.input, InputSource::Stdin);
+        assert!(parsed.json);
+        let parsed = BingoArgs::parse(args(&[])).unwrap();
+        assert_eq!(parsed.input, InputSource::Default);
+        assert!(!parsed.json);
+        assert_eq!(
+            (parsed.trials, parsed.seed, parsed.generate),
+            (None, 0, None)
+        );
+        let parsed = BingoArgs::parse(args(&["--trials", "500", "--seed", "9"])).unwrap();
+        assert_eq!((parsed.trials, parsed.seed), (Some(500), 9));
+        let parsed = BingoArgs::parse(args(&["--generate", "20"])).unwrap();
+        assert_eq!(parsed.generate, Some(20));
+        assert!(BingoArgs::parse(args(&["--trials", "many"])).is_err());
+        assert!(BingoArgs::parse(args(&["--input"])).is_err());
+        assert!(BingoArgs::parse(args(&["4"])).is_err());
+    }
+
+    #[test]
+    fn test_samples() {
+        for day in days::DAYS.iter() {
+            let input = fs::read_to_string(day.sample_path()).unwrap();
+            let budget = Budget {
+                min_samples: 1,
+                max_samples: 1,
+                time: Duration::ZERO,
+            };
+            assert!((day.bench)(&input, &budget).is_ok(), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for day in days::DAYS.iter() {
+            let sample = fs::read_to_string(day.sample_path()).unwrap();
+            let lf = sample.replace("\r\n", "\n");
+            let variants = [
+                lf.trim_end().to_owned(),
+                lf.replace('\n', "\r\n"),
+                format!("\u{feff}{}\n\n", lf),
+            ];
+            for part in [1, 2] {
+                let solve = day.part(part).unwrap();
+                let expected = solve(&lf);
+                assert!(!expected.is_error(), "day {} part {}", day.number, part);
+                for variant in &variants {
+                    assert_eq!(solve(variant), expected, "day {} part {}", day.number, part);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(days::DAYS.len(), 18);
+        assert!(days::find(18).is_some());
+        assert!(days::find(19).is_none());
+        assert!(days::find(1).unwrap().part(3).is_none());
+        let answer = (days::find(2).unwrap().part1)("forward 5\nsideways 2");
+        assert_eq!(
+            answer.to_string(),
+            "error: line 2, column 1: unrecognized move (found \"sideways\")"
+        );
+    }
+}
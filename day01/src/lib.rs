@@ -0,0 +1,304 @@
+use aoc_core::{parse_at, Answer, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+mod report;
+
+pub use report::{Jump, JumpRule, Report, Run};
+
+pub struct Sonar {
+    depths: Vec<i64>,
+}
+
+impl Solution for Sonar {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Sonar {
+            depths: parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        increase_count(pairs(self.depths.iter().copied())).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let sums = window_sums(self.depths.iter().copied(), 3);
+        increase_count(pairs(sums)).into()
+    }
+}
+
+impl Sonar {
+    pub fn new(depths: Vec<i64>) -> Self {
+        Sonar { depths }
+    }
+
+    pub fn report(&self, rule: JumpRule) -> Report {
+        Report::new(&self.depths, rule)
+    }
+}
+
+/// What to do with lines that are not depths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Fail on the first one.
+    #[default]
+    Strict,
+    /// Skip them, and return their errors as warnings.
+    Lenient,
+}
+
+pub fn parse(data: &str) -> aoc_core::Result<Vec<i64>> {
+    read_depths(data.as_bytes()).collect()
+}
+
+/// The depths in `data`, and in lenient mode the errors of the lines that were skipped.
+pub fn parse_with(data: &str, mode: Mode) -> aoc_core::Result<(Vec<i64>, Vec<ParseError>)> {
+    let mut depths = vec![];
+    let mut warnings = vec![];
+    for depth in read_depths(data.as_bytes()) {
+        match (depth, mode) {
+            (Ok(depth), _) => depths.push(depth),
+            (Err(e), Mode::Strict) => return Err(e),
+            (Err(e), Mode::Lenient) => warnings.push(e),
+        }
+    }
+    Ok((depths, warnings))
+}
+
+/// Depths read one line at a time, so that a feed such as stdin can be followed as it is
+/// written. Blank lines and `#` comments are skipped. Read failures are reported at the line
+/// they happened on.
+pub fn read_depths(reader: impl BufRead) -> impl Iterator<Item = aoc_core::Result<i64>> {
+    reader.lines().enumerate().filter_map(|(index, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(ParseError::new(index + 1, 1, "", e.to_string()))),
+        };
+        let depth = line.split('#').next().unwrap_or_default().trim();
+        (!depth.is_empty()).then(|| parse_at(index, &line, depth))
+    })
+}
+
+pub fn pairs<T: Clone>(iter: impl Iterator<Item = T>) -> impl Iterator<Item = (T, T)> {
+    iter.tuple_windows()
+}
+
+pub fn triplets<T: Clone>(iter: impl Iterator<Item = T>) -> impl Iterator<Item = (T, T, T)> {
+    iter.tuple_windows()
+}
+
+/// The sum of every `size` consecutive values, keeping a running sum instead of adding each
+/// window up again. Sums are wide enough for any window of 64-bit depths.
+pub fn window_sums<T: Into<i128>>(
+    iter: impl Iterator<Item = T>,
+    size: usize,
+) -> impl Iterator<Item = i128> {
+    assert!(size > 0, "windows should hold at least one value");
+    let mut window = VecDeque::with_capacity(size + 1);
+    let mut sum = 0;
+    iter.filter_map(move |value| {
+        let value = value.into();
+        window.push_back(value);
+        sum += value;
+        if window.len() > size {
+            sum -= window.pop_front().unwrap();
+        }
+        (window.len() == size).then_some(sum)
+    })
+}
+
+pub fn increase_count<T: PartialOrd>(iter: impl Iterator<Item = (T, T)>) -> usize {
+    iter.filter(|(a, b)| b > a).count()
+}
+
+/// After each value, how many of the values so far were larger than the one before.
+pub fn running_increase_count<T: PartialOrd>(
+    iter: impl Iterator<Item = T>,
+) -> impl Iterator<Item = usize> {
+    let mut previous = None;
+    let mut count = 0;
+    iter.map(move |value| {
+        if previous.as_ref().is_some_and(|previous| value > *previous) {
+            count += 1;
+        }
+        previous = Some(value);
+        count
+    })
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    use std::fs;
+
+    const FILENAME: &str = "input.txt";
+    const DATA1: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+    #[test]
+    fn read_data() {
+        assert_eq!(parse(DATA1).unwrap().len(), 10);
+        let error = parse("199\n2OO").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "2OO");
+        let data = "# depths\n199\n\n  200  # second\n\n";
+        assert_eq!(parse(data).unwrap(), [199, 200]);
+        let error = parse("199\n\n200 # ok\n2OO # not ok").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (4, 1, "2OO")
+        );
+    }
+    #[test]
+    fn modes() {
+        let data = "199\nx\n200\n9999999999999999999\n210";
+        let error = parse_with(data, Mode::Strict).unwrap_err();
+        assert_eq!(error.line, 2);
+        let (depths, warnings) = parse_with(data, Mode::Lenient).unwrap();
+        assert_eq!(depths, [199, 200, 210]);
+        let lines: Vec<usize> = warnings.iter().map(|warning| warning.line).collect();
+        assert_eq!(lines, [2, 4]);
+        assert_eq!(parse_with(DATA1, Mode::Strict).unwrap().1, []);
+    }
+    #[test]
+    fn read_file() {
+        parse(&fs::read_to_string(FILENAME).unwrap()).unwrap();
+    }
+    #[test]
+    fn tuples() {
+        let data = vec![1, 2, 3, 4];
+        let mut pairs = pairs(data.into_iter());
+        assert_eq!(pairs.next(), Some((1, 2)));
+        assert_eq!(pairs.next(), Some((2, 3)));
+        assert_eq!(pairs.next(), Some((3, 4)));
+        assert_eq!(pairs.next(), None);
+    }
+    #[test]
+    fn test_solution() {
+        let count = increase_count(pairs(parse(DATA1).unwrap().into_iter()));
+        assert_eq!(count, 7);
+    }
+    #[test]
+    fn triplets_test() {
+        let data = vec![1, 2, 3, 4];
+        let mut triples = triplets(data.into_iter());
+        assert_eq!(triples.next(), Some((1, 2, 3)));
+        assert_eq!(triples.next(), Some((2, 3, 4)));
+        assert_eq!(triples.next(), None);
+    }
+    #[test]
+    fn part2_test() {
+        let data = parse(DATA1).unwrap();
+        let triplets = triplets(data.into_iter());
+        let pairs = pairs(triplets.map(|(a, b, c)| a + b + c));
+        let increase_count = increase_count(pairs);
+        assert_eq!(increase_count, 5);
+    }
+    #[test]
+    fn window_sums_test() {
+        let sums = window_sums(vec![1, 2, 3, 4].into_iter(), 3).collect::<Vec<i128>>();
+        assert_eq!(sums, vec![6, 9]);
+        assert_eq!(window_sums(vec![1, 2].into_iter(), 3).count(), 0);
+        let data = parse(DATA1).unwrap();
+        let counts = [1, 3, 7, 30]
+            .map(|size| increase_count(pairs(window_sums(data.iter().copied(), size))));
+        assert_eq!(counts, [7, 5, 3, 0]);
+    }
+    #[test]
+    #[should_panic]
+    fn empty_windows() {
+        window_sums(vec![1, 2].into_iter(), 0).count();
+    }
+    #[test]
+    fn streaming() {
+        let counts = running_increase_count(read_depths(DATA1.as_bytes()).map(Result::unwrap));
+        assert_eq!(
+            counts.collect::<Vec<usize>>(),
+            [0, 1, 2, 3, 3, 4, 5, 6, 6, 7]
+        );
+        let mut depths = read_depths("199\r\n200\r\n2O8\r\n210".as_bytes());
+        assert_eq!(depths.next(), Some(Ok(199)));
+        assert_eq!(depths.next(), Some(Ok(200)));
+        assert_eq!(depths.next().unwrap().unwrap_err().line, 3);
+        assert_eq!(depths.next(), Some(Ok(210)));
+        let sums = window_sums(read_depths(DATA1.as_bytes()).map(Result::unwrap), 3);
+        assert_eq!(running_increase_count(sums).last(), Some(5));
+    }
+    #[test]
+    fn part1_answer() {
+        let file_data = fs::read_to_string(FILENAME).unwrap();
+        let data = parse(&file_data).unwrap();
+        assert_eq!(increase_count(pairs(data.iter().copied())), 1616);
+    }
+    #[test]
+    fn part2_answer() {
+        let file_data = fs::read_to_string(FILENAME).unwrap();
+        assert_eq!(
+            increase_count(pairs(
+                triplets(parse(&file_data).unwrap().into_iter()).map(|(a, b, c)| a + b + c)
+            )),
+            1645
+        );
+    }
+
+    #[test]
+    fn large_depths() {
+        let sonar = Sonar::parse("2147483647\n2147483647\n2147483647\n2147483647").unwrap();
+        assert_eq!(sonar.part2(), Answer::from(0));
+        let max = i64::MAX.to_string();
+        let sonar = Sonar::parse(&["-1", &max, &max, &max].join("\n")).unwrap();
+        assert_eq!(
+            (sonar.part1(), sonar.part2()),
+            (Answer::from(1), Answer::from(1))
+        );
+    }
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            let _ = Sonar::parse(&text);
+            let _ = parse_with(&text, Mode::Lenient);
+        }
+
+        #[test]
+        fn parse_round_trip(depths in vec(any::<i64>(), 0..50)) {
+            prop_assert_eq!(parse(&depths.iter().join("\n")).unwrap(), depths);
+        }
+
+        /// The windows `a + b + c` and `b + c + d` differ by `d - a` only.
+        #[test]
+        fn window_sum_differences(depths in vec(any::<i64>(), 0..50)) {
+            let sonar = Sonar { depths: depths.clone() };
+            let expected = depths.windows(4).filter(|w| w[3] > w[0]).count();
+            prop_assert_eq!(sonar.part2(), Answer::from(expected));
+        }
+
+        #[test]
+        fn running_count_ends_at_total(depths in vec(any::<i64>(), 0..50)) {
+            let text = depths.iter().join("\n");
+            let counts = running_increase_count(read_depths(text.as_bytes()).map(Result::unwrap));
+            prop_assert_eq!(
+                counts.last().unwrap_or(0),
+                increase_count(pairs(depths.into_iter()))
+            );
+        }
+
+        #[test]
+        fn window_sums_any_size(depths in vec(any::<i64>(), 0..50), size in 1..10usize) {
+            let sums = window_sums(depths.iter().copied(), size).collect::<Vec<i128>>();
+            let expected = depths
+                .windows(size)
+                .map(|w| w.iter().map(|&depth| depth as i128).sum())
+                .collect::<Vec<i128>>();
+            prop_assert_eq!(sums, expected);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+/// When a change between two consecutive depths counts as a jump.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JumpRule {
+    /// The depth changed by more than this many units.
+    Threshold(i64),
+    /// The change is more than this many standard deviations away from the mean change.
+    ZScore(f64),
+}
+
+/// Consecutive depths that keep going the same way. Positions are indexes in the series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Run {
+    pub start: usize,
+    pub len: usize,
+}
+
+/// A sudden change from the depth at `index - 1` to the one at `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Jump {
+    pub index: usize,
+    pub from: i64,
+    pub to: i64,
+}
+
+impl Jump {
+    pub fn change(&self) -> i128 {
+        self.to as i128 - self.from as i128
+    }
+}
+
+/// A summary of a depth series. Everything but the count is missing when there are no depths.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub count: usize,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub mean: Option<f64>,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub jumps: Vec<Jump>,
+}
+
+impl Report {
+    pub fn new(depths: &[i64], rule: JumpRule) -> Self {
+        let sum: i128 = depths.iter().map(|&depth| depth as i128).sum();
+        Report {
+            count: depths.len(),
+            min: depths.iter().copied().min(),
+            max: depths.iter().copied().max(),
+            mean: (!depths.is_empty()).then(|| sum as f64 / depths.len() as f64),
+            longest_increase: longest_run(depths, |a, b| b > a),
+            longest_decrease: longest_run(depths, |a, b| b < a),
+            jumps: jumps(depths, rule),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports only hold numbers")
+    }
+}
+
+/// The first of the longest runs where every depth follows the previous one, if any depth does.
+fn longest_run(depths: &[i64], follows: impl Fn(i64, i64) -> bool) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut start = 0;
+    for (index, pair) in depths.windows(2).enumerate() {
+        if !follows(pair[0], pair[1]) {
+            start = index + 1;
+            continue;
+        }
+        let run = Run {
+            start,
+            len: index + 2 - start,
+        };
+        if longest.is_none_or(|longest| run.len > longest.len) {
+            longest = Some(run);
+        }
+    }
+    longest
+}
+
+fn jumps(depths: &[i64], rule: JumpRule) -> Vec<Jump> {
+    let jumps = depths.windows(2).enumerate().map(|(index, pair)| Jump {
+        index: index + 1,
+        from: pair[0],
+        to: pair[1],
+    });
+    match rule {
+        JumpRule::Threshold(threshold) => jumps
+            .filter(|jump| jump.change().abs() > threshold as i128)
+            .collect(),
+        JumpRule::ZScore(z) => {
+            let changes: Vec<f64> = jumps.clone().map(|jump| jump.change() as f64).collect();
+            let count = changes.len() as f64;
+            let mean = changes.iter().sum::<f64>() / count;
+            let variance = changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / count;
+            let deviation = variance.sqrt();
+            // When every change is the same, none of them stands out.
+            if changes.is_empty() || deviation == 0.0 {
+                return vec![];
+            }
+            jumps
+                .filter(|jump| ((jump.change() as f64 - mean) / deviation).abs() > z)
+                .collect()
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "depths: {}", self.count)?;
+        if let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean) {
+            writeln!(f, "min: {}", min)?;
+            writeln!(f, "max: {}", max)?;
+            writeln!(f, "mean: {:.2}", mean)?;
+        }
+        for (name, run) in [
+            ("increase", self.longest_increase),
+            ("decrease", self.longest_decrease),
+        ] {
+            match run {
+                Some(run) => writeln!(
+                    f,
+                    "longest {}: {} depths from index {}",
+                    name, run.len, run.start
+                )?,
+                None => writeln!(f, "longest {}: none", name)?,
+            }
+        }
+        write!(f, "jumps: {}", self.jumps.len())?;
+        for jump in &self.jumps {
+            write!(
+                f,
+                "\n  index {}: {} -> {} ({:+})",
+                jump.index,
+                jump.from,
+                jump.to,
+                jump.change()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_summary() {
+        let report = Report::new(&DEPTHS, JumpRule::Threshold(20));
+        assert_eq!(
+            (report.count, report.min, report.max),
+            (10, Some(199), Some(269))
+        );
+        assert_eq!(report.mean, Some(225.6));
+        assert_eq!(report.longest_increase, Some(Run { start: 0, len: 4 }));
+        assert_eq!(report.longest_decrease, Some(Run { start: 3, len: 2 }));
+        let jumps =
+            [(6, 207, 240), (7, 240, 269)].map(|(index, from, to)| Jump { index, from, to });
+        assert_eq!(report.jumps, jumps);
+    }
+
+    #[test]
+    fn test_empty() {
+        let report = Report::new(&[], JumpRule::ZScore(2.0));
+        assert_eq!((report.count, report.min, report.mean), (0, None, None));
+        assert_eq!(report.longest_increase, None);
+        assert!(report.jumps.is_empty());
+        let report = Report::new(&[5, 5, 5], JumpRule::ZScore(0.0));
+        assert_eq!(report.longest_decrease, None);
+        assert!(report.jumps.is_empty());
+    }
+
+    #[test]
+    fn test_z_score() {
+        let report = Report::new(&[1, 2, 3, 4, 20, 21, 22], JumpRule::ZScore(2.0));
+        assert_eq!(
+            report.jumps,
+            [Jump {
+                index: 4,
+                from: 4,
+                to: 20
+            }]
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        let report = Report::new(&DEPTHS, JumpRule::Threshold(30));
+        assert_eq!(
+            report.to_string(),
+            "depths: 10
+min: 199
+max: 269
+mean: 225.60
+longest increase: 4 depths from index 0
+longest decrease: 2 depths from index 3
+jumps: 1
+  index 6: 207 -> 240 (+33)"
+        );
+        assert_eq!(
+            Report::new(&[], JumpRule::Threshold(30))
+                .to_string()
+                .lines()
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_large_depths() {
+        let report = Report::new(&[i64::MIN, i64::MAX], JumpRule::Threshold(i64::MAX));
+        assert_eq!(report.mean, Some(-0.5));
+        assert_eq!(report.jumps[0].change(), u64::MAX as i128);
+    }
+
+    #[test]
+    fn test_json() {
+        let report = Report::new(&[3, 1], JumpRule::Threshold(1));
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["mean"], 2.0);
+        assert_eq!(json["longest_increase"], serde_json::Value::Null);
+        assert_eq!(json["longest_decrease"]["len"], 2);
+        assert_eq!(json["jumps"][0]["to"], 1);
+    }
+}
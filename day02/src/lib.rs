@@ -0,0 +1,213 @@
+use aoc_core::{expect_part, parse_at, Answer, ParseError, Solution};
+use std::fmt::Display;
+
+mod model;
+mod planner;
+mod script;
+mod trace;
+
+pub use model::{
+    find_model, models, run_models, Aim, Drag, Plain, State, Submarine, SubmarineModel, Surfaced,
+};
+pub use planner::{Bounds, Plan};
+pub use script::{Moves, Script};
+pub use trace::Trace;
+
+/// The puzzle input, read as a script.
+pub struct Course {
+    script: Script,
+}
+
+impl Solution for Course {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Course {
+            script: Script::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut pos = Pos::new();
+        pos.execute(&self.script);
+        overflow_error(pos.product())
+    }
+
+    fn part2(&self) -> Answer {
+        let mut pos = Pos2::new();
+        pos.execute(&self.script);
+        overflow_error(pos.product())
+    }
+}
+
+/// The product, or an error saying that the course went out of range.
+fn overflow_error(product: Option<i64>) -> Answer {
+    product.map_or_else(|| Answer::Error("overflow".to_owned()), Answer::from)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Fwd(i32),
+    /// Forward, in reverse.
+    Back(i32),
+    Up(i32),
+    Down(i32),
+}
+
+impl Move {
+    /// How to make the move called `name`, if there is one.
+    pub fn named(name: &str) -> Option<fn(i32) -> Move> {
+        match name {
+            "forward" => Some(Move::Fwd),
+            "back" => Some(Move::Back),
+            "up" => Some(Move::Up),
+            "down" => Some(Move::Down),
+            _ => None,
+        }
+    }
+
+    /// Parses line `index` of the course.
+    pub fn parse(index: usize, str: &str) -> aoc_core::Result<Self> {
+        let mut split = str.split(' ');
+        let first = expect_part(index, str, split.next().filter(|s| !s.is_empty()), "a move")?;
+        let second = parse_at(
+            index,
+            str,
+            expect_part(index, str, split.next(), "an amount")?,
+        )?;
+        match Move::named(first) {
+            Some(make) => Ok(make(second)),
+            None => Err(ParseError::at(index, str, first, "unrecognized move")),
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Move::Fwd(x) => write!(f, "forward {}", x),
+            Move::Back(x) => write!(f, "back {}", x),
+            Move::Up(x) => write!(f, "up {}", x),
+            Move::Down(x) => write!(f, "down {}", x),
+        }
+    }
+}
+
+/// The part 1 submarine.
+pub type Pos = Submarine<Plain>;
+
+/// The part 2 submarine.
+pub type Pos2 = Submarine<Aim>;
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    const DATA: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+    #[test]
+    fn test_sample() {
+        let script = Course::parse(DATA).unwrap().script;
+        let mut pos = Pos::new();
+        script.moves().for_each(|m| pos.apply_move(m));
+        assert_eq!(pos.product(), Some(150));
+    }
+    #[test]
+    fn test_part2() {
+        let script = Course::parse(DATA).unwrap().script;
+        let mut pos = Pos2::new();
+        script.moves().for_each(|m| pos.apply_move(m));
+        assert_eq!(pos.product(), Some(900));
+    }
+    #[test]
+    fn test_script() {
+        let course =
+            Course::parse("def step { forward 5 down 5 }\nrepeat 2 { step }\nback 5").unwrap();
+        assert_eq!(course.part1(), Answer::from(5 * 10));
+        // Forward 5 at aim 5 goes 25 down, back 5 at aim 10 goes 50 up.
+        assert_eq!(course.part2(), Answer::from(5 * (25 - 50)));
+    }
+    #[test]
+    fn test_overflow() {
+        let course = Course::parse("repeat 5 { forward 2147483647 down 2147483647 }").unwrap();
+        assert_eq!(course.part1(), Answer::Error("overflow".into()));
+        assert_eq!(course.part2(), Answer::Error("overflow".into()));
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = Move::parse(3, "sideways 2").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.text, "sideways");
+        assert_eq!(Move::parse(0, "back 2"), Ok(Move::Back(2)));
+        let error = Move::parse(0, "up two").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(Move::parse(0, "up").is_err());
+        assert!(Move::parse(0, "").is_err());
+    }
+    #[test]
+    fn test_large_course() {
+        let course = Course::parse(&"forward 9\ndown 9\n".repeat(1000)).unwrap();
+        assert_eq!(course.part1(), Answer::from(81_000_000));
+        assert_eq!(course.part2(), Answer::from(364_135_500_000i64));
+    }
+
+    fn moves(max_len: usize) -> impl Strategy<Value = Vec<Move>> {
+        let amount = 0..1000;
+        let step = prop_oneof![
+            amount.clone().prop_map(Move::Fwd),
+            amount.clone().prop_map(Move::Back),
+            amount.clone().prop_map(Move::Up),
+            amount.prop_map(Move::Down),
+        ];
+        vec(step, 0..max_len)
+    }
+
+    fn format(moves: &[Move]) -> String {
+        moves
+            .iter()
+            .map(Move::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*|[a-z0-9{}# \n]{0,64}") {
+            if let Ok(course) = Course::parse(&text) {
+                course.script.moves().take(10_000).count();
+            }
+        }
+
+        /// Repeating a block runs the same moves as writing it out.
+        #[test]
+        fn repeat_is_unrolled(moves in vec(moves(20), 1..4), count in 0..5u32) {
+            let body = moves.iter().map(|m| format(m)).collect::<Vec<_>>().join("\n");
+            let script = Script::parse(&format!("repeat {} {{\n{}\n}}", count, body)).unwrap();
+            let unrolled = Script::parse(&vec![body; count as usize].join("\n")).unwrap();
+            prop_assert_eq!(script.len(), unrolled.len());
+            prop_assert!(script.moves().eq(unrolled.moves()));
+        }
+
+        #[test]
+        fn parse_round_trip(moves in moves(1000)) {
+            let script = Course::parse(&format(&moves)).unwrap().script;
+            prop_assert_eq!(script.moves().copied().collect::<Vec<Move>>(), moves);
+        }
+
+        /// Aim in part 2 moves exactly like depth in part 1.
+        #[test]
+        fn aim_is_depth(moves in moves(1000)) {
+            let mut pos = Pos::new();
+            let mut pos2 = Pos2::new();
+            for m in &moves {
+                pos.apply_move(m);
+                pos2.apply_move(m);
+            }
+            let (state, state2) = (pos.state().unwrap(), pos2.state().unwrap());
+            prop_assert_eq!(state2.horizontal, state.horizontal);
+            prop_assert_eq!(state2.aim, state.depth);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{Move, Script};
+
+/// Where a submarine is. Models without aim leave it at 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct State {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl State {
+    /// The puzzle answer, or `None` if it does not fit in an `i64`.
+    pub fn product(&self) -> Option<i64> {
+        self.horizontal.checked_mul(self.depth)
+    }
+}
+
+/// A way of reading moves. Models only say how one move changes the state, so that they can be
+/// combined and run side by side.
+pub trait SubmarineModel {
+    /// A short name, to tell results apart.
+    fn name(&self) -> String;
+
+    /// The state after move `m`, or `None` if it overflows, which scripts repeating moves a
+    /// lot can make happen.
+    fn step(&self, state: State, m: &Move) -> Option<State>;
+}
+
+/// Part 1: up and down change the depth directly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Plain;
+
+impl SubmarineModel for Plain {
+    fn name(&self) -> String {
+        "plain".to_owned()
+    }
+
+    fn step(&self, mut state: State, m: &Move) -> Option<State> {
+        match *m {
+            Move::Fwd(x) => state.horizontal = state.horizontal.checked_add(x as i64)?,
+            Move::Back(x) => state.horizontal = state.horizontal.checked_sub(x as i64)?,
+            Move::Up(x) => state.depth = state.depth.checked_sub(x as i64)?,
+            Move::Down(x) => state.depth = state.depth.checked_add(x as i64)?,
+        }
+        Some(state)
+    }
+}
+
+/// Part 2: up and down turn the submarine, and going forward or back follows the aim.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Aim;
+
+impl SubmarineModel for Aim {
+    fn name(&self) -> String {
+        "aim".to_owned()
+    }
+
+    fn step(&self, mut state: State, m: &Move) -> Option<State> {
+        let advance = |mut state: State, x: i64| {
+            state.horizontal = state.horizontal.checked_add(x)?;
+            state.depth = state.depth.checked_add(x.checked_mul(state.aim)?)?;
+            Some(state)
+        };
+        match *m {
+            Move::Fwd(x) => return advance(state, x as i64),
+            Move::Back(x) => return advance(state, -(x as i64)),
+            Move::Up(x) => state.aim = state.aim.checked_sub(x as i64)?,
+            Move::Down(x) => state.aim = state.aim.checked_add(x as i64)?,
+        }
+        Some(state)
+    }
+}
+
+/// Aim that straightens out: every forward or back move loses `percent` of the aim, rounded
+/// towards 0, once the move is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Drag {
+    pub percent: i64,
+}
+
+impl SubmarineModel for Drag {
+    fn name(&self) -> String {
+        format!("drag-{}", self.percent)
+    }
+
+    fn step(&self, state: State, m: &Move) -> Option<State> {
+        let mut state = Aim.step(state, m)?;
+        if let Move::Fwd(_) | Move::Back(_) = m {
+            state.aim = state
+                .aim
+                .checked_sub(state.aim.checked_mul(self.percent)? / 100)?;
+        }
+        Some(state)
+    }
+}
+
+/// Another model, except that the submarine cannot rise above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Surfaced<M>(pub M);
+
+impl<M: SubmarineModel> SubmarineModel for Surfaced<M> {
+    fn name(&self) -> String {
+        format!("surfaced-{}", self.0.name())
+    }
+
+    fn step(&self, state: State, m: &Move) -> Option<State> {
+        let mut state = self.0.step(state, m)?;
+        state.depth = state.depth.max(0);
+        Some(state)
+    }
+}
+
+/// A model together with the state it has reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submarine<M> {
+    model: M,
+    /// `None` once a move overflowed.
+    state: Option<State>,
+}
+
+impl<M: SubmarineModel + Default> Submarine<M> {
+    pub fn new() -> Self {
+        Self::with_model(M::default())
+    }
+}
+
+impl<M: SubmarineModel + Default> Default for Submarine<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: SubmarineModel> Submarine<M> {
+    pub fn with_model(model: M) -> Self {
+        Submarine {
+            model,
+            state: Some(State::default()),
+        }
+    }
+
+    /// Where the submarine is, or `None` if getting there overflowed.
+    pub fn state(&self) -> Option<State> {
+        self.state
+    }
+
+    pub fn apply_move(&mut self, m: &Move) {
+        self.state = self.state.and_then(|state| self.model.step(state, m));
+    }
+
+    /// Runs the script, stopping early if a move overflows.
+    pub fn execute(&mut self, script: &Script) {
+        for m in script.moves() {
+            self.apply_move(m);
+            if self.state.is_none() {
+                break;
+            }
+        }
+    }
+
+    pub fn product(&self) -> Option<i64> {
+        self.state?.product()
+    }
+}
+
+/// Every model the runner knows about, by name.
+pub fn models() -> Vec<Box<dyn SubmarineModel>> {
+    vec![
+        Box::new(Plain),
+        Box::new(Aim),
+        Box::new(Drag { percent: 20 }),
+        Box::new(Surfaced(Plain)),
+        Box::new(Surfaced(Aim)),
+    ]
+}
+
+pub fn find_model(name: &str) -> Option<Box<dyn SubmarineModel>> {
+    models().into_iter().find(|model| model.name() == name)
+}
+
+/// Runs the same moves through every model at once, and gives the state each one ends in, or
+/// `None` for the models that overflowed.
+pub fn run_models<'a>(
+    moves: impl IntoIterator<Item = &'a Move>,
+    models: &[Box<dyn SubmarineModel>],
+) -> Vec<Option<State>> {
+    let mut states = vec![Some(State::default()); models.len()];
+    for m in moves {
+        for (state, model) in states.iter_mut().zip(models) {
+            *state = state.and_then(|state| model.step(state, m));
+        }
+    }
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [Move; 6] = [
+        Move::Fwd(5),
+        Move::Down(5),
+        Move::Fwd(8),
+        Move::Up(3),
+        Move::Down(8),
+        Move::Fwd(2),
+    ];
+
+    #[test]
+    fn test_run_models() {
+        let states: Vec<State> = run_models(&SAMPLE, &models())
+            .into_iter()
+            .flatten()
+            .collect();
+        let products: Vec<i64> = states.iter().filter_map(State::product).collect();
+        // Drag takes aim 5 down to 4 after forward 8, so forward 2 goes at aim 9 instead of 10.
+        assert_eq!(products, [150, 900, 15 * (40 + 18), 150, 900]);
+        assert_eq!(states[1].aim, 10);
+        assert!(run_models(&SAMPLE, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_surfaced() {
+        let moves = [Move::Up(3), Move::Fwd(2), Move::Down(1)];
+        let states = run_models(&moves, &[Box::new(Surfaced(Plain)), Box::new(Plain)]);
+        let depths: Vec<i64> = states.iter().flatten().map(|state| state.depth).collect();
+        assert_eq!(depths, [1, -2]);
+        let mut submarine = Submarine::with_model(Surfaced(Aim));
+        moves.iter().for_each(|m| submarine.apply_move(m));
+        assert_eq!(submarine.state().unwrap().depth, 0);
+        assert_eq!(submarine.state().unwrap().aim, -2);
+    }
+
+    #[test]
+    fn test_names() {
+        let names: Vec<String> = models().iter().map(|model| model.name()).collect();
+        assert_eq!(
+            names,
+            ["plain", "aim", "drag-20", "surfaced-plain", "surfaced-aim"]
+        );
+        assert_eq!(find_model("drag-20").unwrap().name(), "drag-20");
+        assert!(find_model("submerged").is_none());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let moves = [Move::Down(i32::MAX), Move::Fwd(i32::MAX)].repeat(3);
+        let states = run_models(&moves, &[Box::new(Aim), Box::new(Drag { percent: 20 })]);
+        assert_eq!(states, [None, None]);
+        let states = run_models(&moves[..2], &[Box::new(Aim), Box::new(Plain)]);
+        let aim = states[0].unwrap();
+        assert_eq!(aim.depth, i32::MAX as i64 * i32::MAX as i64);
+        assert_eq!(aim.product(), None);
+        assert_eq!(
+            states[1].unwrap().product(),
+            Some(i32::MAX as i64 * i32::MAX as i64)
+        );
+        let far = State {
+            horizontal: i64::MAX - 1,
+            ..State::default()
+        };
+        assert!(Plain.step(far, &Move::Fwd(1)).is_some());
+        assert_eq!(Surfaced(Plain).step(far, &Move::Fwd(2)), None);
+        let mut submarine = Submarine::with_model(Aim);
+        moves[..3].iter().for_each(|m| submarine.apply_move(m));
+        assert!(submarine.state().is_some());
+        // Three times i32::MAX squared is too deep.
+        submarine.apply_move(&moves[3]);
+        assert_eq!(submarine.state(), None);
+        submarine.apply_move(&Move::Back(i32::MAX));
+        assert_eq!(submarine.product(), None);
+    }
+}
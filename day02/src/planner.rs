@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+
+use crate::{Aim, Move, Plain, State, SubmarineModel};
+
+/// Limits on the moves of a plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    /// The largest amount of a single move. Puzzle inputs go up to 9.
+    pub max_amount: i32,
+    /// How much a single move may change the depth by, if that is limited.
+    pub max_depth_step: Option<i64>,
+    /// The longest plan worth looking for.
+    pub max_moves: usize,
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Bounds {
+            max_amount: 9,
+            max_depth_step: None,
+            max_moves: 30,
+        }
+    }
+}
+
+impl Bounds {
+    fn allows(&self, from: State, to: State) -> bool {
+        self.max_depth_step
+            .is_none_or(|step| (to.depth as i128 - from.depth as i128).abs() <= step as i128)
+    }
+}
+
+/// Models that can work out how to get somewhere.
+pub trait Plan: SubmarineModel {
+    /// One of the shortest lists of moves that keep to `bounds` and take a submarine from the
+    /// surface to `horizontal` and `depth`, or `None` if it takes more than `bounds.max_moves`.
+    fn plan(&self, horizontal: i64, depth: i64, bounds: &Bounds) -> Option<Vec<Move>>;
+}
+
+impl Plan for Plain {
+    /// Every move changes one coordinate, so each one is covered with as few moves as it takes.
+    fn plan(&self, horizontal: i64, depth: i64, bounds: &Bounds) -> Option<Vec<Move>> {
+        let max = bounds.max_amount as i64;
+        let mut moves = split(horizontal, max, bounds.max_moves, Move::Fwd, Move::Back)?;
+        let max = bounds.max_depth_step.map_or(max, |step| step.min(max));
+        let left = bounds.max_moves - moves.len();
+        moves.extend(split(depth, max, left, Move::Down, Move::Up)?);
+        Some(moves)
+    }
+}
+
+impl Plan for Aim {
+    /// An iterative deepening search, which only follows moves that can still reach the target
+    /// in time. That keeps it quick for plans of a few dozen moves.
+    fn plan(&self, horizontal: i64, depth: i64, bounds: &Bounds) -> Option<Vec<Move>> {
+        let target = State {
+            horizontal,
+            depth,
+            aim: 0,
+        };
+        let mut search = Search {
+            target,
+            bounds,
+            moves: vec![],
+            visited: HashMap::new(),
+        };
+        let start = State::default();
+        let mut limit = search.moves_needed(start, bounds.max_moves)?;
+        while limit <= bounds.max_moves {
+            search.visited.clear();
+            if search.find(start, limit) {
+                return Some(search.moves);
+            }
+            limit += 1;
+        }
+        None
+    }
+}
+
+/// `total` in moves of at most `max`, the largest first, if that takes no more than
+/// `max_moves` moves. The count is checked before any move is made, as a far target with a
+/// small `max` would take more moves than fit in memory.
+fn split(
+    total: i64,
+    max: i64,
+    max_moves: usize,
+    positive: fn(i32) -> Move,
+    negative: fn(i32) -> Move,
+) -> Option<Vec<Move>> {
+    if total == 0 {
+        return Some(vec![]);
+    }
+    if max < 1 {
+        return None;
+    }
+    let make = if total > 0 { positive } else { negative };
+    let mut left = total.unsigned_abs();
+    let count = left.div_ceil(max as u64);
+    if count > max_moves as u64 {
+        return None;
+    }
+    let mut moves = Vec::with_capacity(count as usize);
+    while left > 0 {
+        let amount = left.min(max as u64);
+        moves.push(make(amount as i32));
+        left -= amount;
+    }
+    Some(moves)
+}
+
+struct Search<'a> {
+    target: State,
+    bounds: &'a Bounds,
+    /// The moves from the start to the state being looked at.
+    moves: Vec<Move>,
+    /// The fewest moves each state was reached in, in this round.
+    visited: HashMap<State, usize>,
+}
+
+impl Search<'_> {
+    /// Whether the target can be reached from `state` in `limit` more moves, which are then
+    /// added to `self.moves`.
+    fn find(&mut self, state: State, limit: usize) -> bool {
+        if (state.horizontal, state.depth) == (self.target.horizontal, self.target.depth) {
+            return true;
+        }
+        if self.moves_needed(state, limit).is_none() {
+            return false;
+        }
+        let used = self.moves.len();
+        if self.visited.get(&state).is_some_and(|&seen| seen <= used) {
+            return false;
+        }
+        self.visited.insert(state, used);
+        for amount in (1..=self.bounds.max_amount).rev() {
+            for make in [Move::Fwd, Move::Back, Move::Down, Move::Up] {
+                let m = make(amount);
+                let Some(next) = Aim.step(state, &m) else {
+                    continue;
+                };
+                if !self.bounds.allows(state, next) {
+                    continue;
+                }
+                self.moves.push(m);
+                if self.find(next, limit - 1) {
+                    return true;
+                }
+                self.moves.pop();
+            }
+        }
+        false
+    }
+
+    /// A lower bound on the moves from `state` to the target, if it is at most `limit`.
+    ///
+    /// With `n` forward or back moves left and the aim at `a`, the depth still to cover is
+    /// `a * Δh` plus the sum, over the aim changes to come, of each change times the distance
+    /// travelled after it. That distance is at most `(n * max + |Δh|) / 2`, since whatever is
+    /// travelled beyond `Δh` has to be travelled back.
+    fn moves_needed(&self, state: State, limit: usize) -> Option<usize> {
+        let max = self.bounds.max_amount as i128;
+        let dh = self.target.horizontal as i128 - state.horizontal as i128;
+        let dd = self.target.depth as i128 - state.depth as i128;
+        let rest = dd - state.aim as i128 * dh;
+        let mut fewest = (dh.unsigned_abs()).div_ceil(max.max(1) as u128) as usize;
+        match self.bounds.max_depth_step {
+            Some(step) if step <= 0 && dd != 0 => return None,
+            Some(step) if step > 0 => {
+                fewest = fewest.max(dd.unsigned_abs().div_ceil(step as u128) as usize)
+            }
+            _ => {}
+        }
+        if rest == 0 {
+            return (fewest <= limit).then_some(fewest);
+        }
+        // Changing the depth needs an aim change and some travel, there and back if the
+        // horizontal position is already right.
+        fewest = fewest.max(if dh == 0 { 2 } else { 1 });
+        (fewest..limit)
+            .map(|n| {
+                let reach = max * (n as i128 * max + dh.abs()) / 2;
+                let turns = match reach {
+                    0 => limit as u128,
+                    _ => rest.unsigned_abs().div_ceil(reach as u128).max(1),
+                };
+                n + turns.min(limit as u128) as usize
+            })
+            .min()
+            .filter(|&needed| needed <= limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_models;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    fn reaches(model: impl SubmarineModel + 'static, moves: &[Move]) -> (i64, i64) {
+        let state = run_models(moves, &[Box::new(model)])[0].unwrap();
+        (state.horizontal, state.depth)
+    }
+
+    /// The fewest moves to every position within `moves` moves, found the slow way.
+    fn shortest(bounds: &Bounds, moves: usize) -> HashMap<(i64, i64), usize> {
+        let mut shortest = HashMap::from([((0, 0), 0)]);
+        let mut seen = HashMap::from([(State::default(), 0)]);
+        let mut queue = VecDeque::from([State::default()]);
+        while let Some(state) = queue.pop_front() {
+            let used = seen[&state];
+            if used == moves {
+                continue;
+            }
+            for amount in 1..=bounds.max_amount {
+                for make in [Move::Fwd, Move::Back, Move::Down, Move::Up] {
+                    let next = Aim.step(state, &make(amount)).unwrap();
+                    if bounds.allows(state, next) && !seen.contains_key(&next) {
+                        seen.insert(next, used + 1);
+                        shortest
+                            .entry((next.horizontal, next.depth))
+                            .or_insert(used + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        shortest
+    }
+
+    #[test]
+    fn test_plain() {
+        let moves = Plain.plan(15, 10, &Bounds::default()).unwrap();
+        assert_eq!(
+            moves,
+            [Move::Fwd(9), Move::Fwd(6), Move::Down(9), Move::Down(1)]
+        );
+        let bounds = Bounds {
+            max_amount: 20,
+            max_depth_step: Some(4),
+            ..Bounds::default()
+        };
+        let moves = Plain.plan(-15, -10, &bounds).unwrap();
+        assert_eq!(
+            moves,
+            [Move::Back(15), Move::Up(4), Move::Up(4), Move::Up(2)]
+        );
+        assert_eq!(reaches(Plain, &moves), (-15, -10));
+        assert_eq!(Plain.plan(0, 0, &bounds), Some(vec![]));
+        assert_eq!(Plain.plan(300, 0, &Bounds::default()), None);
+        let bounds = Bounds {
+            max_depth_step: Some(0),
+            ..Bounds::default()
+        };
+        assert_eq!(Plain.plan(0, 1, &bounds), None);
+        // Too far away to make every move first and count them after.
+        let bounds = Bounds::default();
+        assert_eq!(Plain.plan(i64::MAX, 0, &bounds), None);
+        assert_eq!(Plain.plan(0, i64::MIN, &bounds), None);
+        assert_eq!(Aim.plan(i64::MAX, i64::MIN, &bounds), None);
+        assert_eq!(Plain.plan(9 * 30, 0, &bounds).map(|m| m.len()), Some(30));
+        assert_eq!(Plain.plan(9 * 30 + 1, 0, &bounds), None);
+        assert_eq!(Plain.plan(9 * 20, 9 * 10 + 1, &bounds), None);
+    }
+
+    #[test]
+    fn test_aim() {
+        let bounds = Bounds::default();
+        let moves = Aim.plan(15, 60, &bounds).unwrap();
+        assert_eq!(moves.len(), 3);
+        assert_eq!(reaches(Aim, &moves), (15, 60));
+        // Going down without going anywhere takes a trip there and back.
+        let moves = Aim.plan(0, -37, &bounds).unwrap();
+        assert_eq!(reaches(Aim, &moves), (0, -37));
+        assert_eq!(moves.len(), 5);
+        assert_eq!(Aim.plan(0, 0, &bounds), Some(vec![]));
+        assert_eq!(Aim.plan(-2, 0, &bounds), Some(vec![Move::Back(2)]));
+        let moves = Aim.plan(100, 2000, &bounds).unwrap();
+        assert_eq!(reaches(Aim, &moves), (100, 2000));
+        assert_eq!(moves.len(), 15);
+    }
+
+    #[test]
+    fn test_aim_bounds() {
+        let bounds = Bounds {
+            max_amount: 5,
+            max_depth_step: Some(10),
+            max_moves: 12,
+        };
+        let moves = Aim.plan(10, 40, &bounds).unwrap();
+        assert_eq!(reaches(Aim, &moves), (10, 40));
+        let states = crate::Trace::record(&moves, &[Box::new(Aim)]).unwrap();
+        let depths: Vec<i64> = states.states(0).map(|state| state.depth).collect();
+        assert!(depths.windows(2).all(|w| (w[1] - w[0]).abs() <= 10));
+        assert_eq!(Aim.plan(10, 200, &bounds), None);
+        let flat = Bounds {
+            max_depth_step: Some(0),
+            ..bounds
+        };
+        assert_eq!(Aim.plan(3, 1, &flat), None);
+    }
+
+    #[test]
+    fn test_shortest() {
+        for bounds in [
+            Bounds {
+                max_amount: 3,
+                max_depth_step: None,
+                max_moves: 4,
+            },
+            Bounds {
+                max_amount: 3,
+                max_depth_step: Some(4),
+                max_moves: 4,
+            },
+        ] {
+            let shortest = shortest(&bounds, bounds.max_moves);
+            for horizontal in -8..=8 {
+                for depth in -30..=30 {
+                    let plan = Aim.plan(horizontal, depth, &bounds);
+                    let expected = shortest.get(&(horizontal, depth)).copied();
+                    assert_eq!(
+                        plan.as_ref().map(Vec::len),
+                        expected,
+                        "{} {}",
+                        horizontal,
+                        depth
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn plans_reach_the_target(horizontal in -30..30i64, depth in -200..200i64) {
+            let bounds = Bounds::default();
+            let moves = Plain.plan(horizontal, depth, &bounds).unwrap();
+            prop_assert_eq!(reaches(Plain, &moves), (horizontal, depth));
+            let moves = Aim.plan(horizontal, depth, &bounds).unwrap();
+            prop_assert_eq!(reaches(Aim, &moves), (horizontal, depth));
+            let in_bounds = moves.iter().all(|m| match *m {
+                Move::Fwd(x) | Move::Back(x) | Move::Up(x) | Move::Down(x) => (1..=9).contains(&x),
+            });
+            prop_assert!(in_bounds);
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! A small language for submarine courses. Plain puzzle input is a valid script:
+//!
+//! ```text
+//! # comments run to the end of the line
+//! def zigzag {
+//!     down 2
+//!     forward 3
+//!     up 2
+//! }
+//! repeat 4 {
+//!     zigzag
+//!     back 1
+//! }
+//! forward 5
+//! ```
+//!
+//! Macros are defined at the top level, before they are used. Blocks nest at most 64 deep, and
+//! a script makes at most 100 million moves once its repeats and macros are expanded, so that
+//! a short script cannot keep the submarine busy for ever.
+
+use aoc_core::{parse_at, ParseError};
+use std::collections::HashMap;
+
+use crate::Move;
+
+const KEYWORDS: [&str; 6] = ["forward", "back", "up", "down", "repeat", "def"];
+
+const MAX_DEPTH: usize = 64;
+
+/// The most moves a script can make, which take about a second to run.
+const MAX_MOVES: u64 = 100_000_000;
+
+/// A word or a brace, and where it was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token<'a> {
+    /// The line holding the token, counted from 0.
+    index: usize,
+    line: &'a str,
+    text: &'a str,
+}
+
+impl Token<'_> {
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, self.line, self.text, message)
+    }
+}
+
+/// Splits `text` into words and braces, leaving out whitespace and comments.
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    for (index, line) in text.lines().enumerate() {
+        let code = line.split('#').next().unwrap_or_default();
+        let mut start = None;
+        for (offset, c) in code.char_indices().chain([(code.len(), ' ')]) {
+            if c.is_whitespace() || c == '{' || c == '}' {
+                if let Some(start) = start.take() {
+                    tokens.push(Token {
+                        index,
+                        line,
+                        text: &code[start..offset],
+                    });
+                }
+                if c == '{' || c == '}' {
+                    tokens.push(Token {
+                        index,
+                        line,
+                        text: &code[offset..offset + 1],
+                    });
+                }
+            } else if start.is_none() {
+                start = Some(offset);
+            }
+        }
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Statement {
+    Move(Move),
+    Repeat(u32, Block),
+    /// A macro, by its index in `Script::macros`.
+    Call(usize),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Block {
+    statements: Vec<Statement>,
+    /// How many moves running the block makes, so that empty blocks can be skipped.
+    len: u64,
+}
+
+impl Block {
+    /// Counts `moves` more moves, made by the statement starting at `token`.
+    fn add_moves(&mut self, moves: Option<u64>, token: &Token) -> aoc_core::Result<()> {
+        match moves.and_then(|moves| self.len.checked_add(moves)) {
+            Some(len) if len <= MAX_MOVES => {
+                self.len = len;
+                Ok(())
+            }
+            _ => Err(token.error(format!("scripts make at most {} moves", MAX_MOVES))),
+        }
+    }
+}
+
+/// A parsed course script. Its moves are generated as they are run, so that repeats do not
+/// have to fit in memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Script {
+    main: Block,
+    macros: Vec<Block>,
+}
+
+struct Parser<'a> {
+    tokens: std::vec::IntoIter<Token<'a>>,
+    /// The last line, where errors about a missing end go.
+    last_line: (usize, &'a str),
+    macro_names: HashMap<&'a str, usize>,
+    macros: Vec<Block>,
+    /// How many blocks the parser is inside of.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// An error at the end of the script, which stopped before `what`.
+    fn missing(&self, what: &str) -> ParseError {
+        let (index, line) = self.last_line;
+        let column = line.chars().count() + 1;
+        ParseError::new(index + 1, column, line, format!("{} expected", what))
+    }
+
+    fn next_or(&mut self, what: &str) -> aoc_core::Result<Token<'a>> {
+        match self.tokens.next() {
+            Some(token) => Ok(token),
+            None => Err(self.missing(what)),
+        }
+    }
+
+    fn open_brace(&mut self) -> aoc_core::Result<()> {
+        let token = self.next_or("{")?;
+        if token.text != "{" {
+            return Err(token.error("{ expected"));
+        }
+        Ok(())
+    }
+
+    /// Statements up to a closing brace, or to the end for the top level.
+    fn block(&mut self, top_level: bool) -> aoc_core::Result<Block> {
+        let mut block = Block::default();
+        loop {
+            let token = match self.tokens.next() {
+                Some(token) => token,
+                None if top_level => return Ok(block),
+                None => return Err(self.missing("}")),
+            };
+            let statement = match token.text {
+                "}" if !top_level => return Ok(block),
+                "}" => return Err(token.error("unmatched }")),
+                "{" => return Err(token.error("command expected")),
+                "def" if !top_level => {
+                    return Err(token.error("macros are defined at the top level"))
+                }
+                "def" => {
+                    self.definition()?;
+                    continue;
+                }
+                "repeat" => {
+                    let count = self.next_or("a repeat count")?;
+                    let count: u32 = parse_at(count.index, count.line, count.text)?;
+                    self.open_brace()?;
+                    let body = self.nested_block(token)?;
+                    block.add_moves(body.len.checked_mul(count as u64), &token)?;
+                    Statement::Repeat(count, body)
+                }
+                name => match Move::named(name) {
+                    Some(make) => {
+                        let amount = self.next_or("an amount")?;
+                        let amount = parse_at(amount.index, amount.line, amount.text)?;
+                        block.add_moves(Some(1), &token)?;
+                        Statement::Move(make(amount))
+                    }
+                    None => {
+                        let index = *self
+                            .macro_names
+                            .get(name)
+                            .ok_or_else(|| token.error("unrecognized move"))?;
+                        block.add_moves(Some(self.macros[index].len), &token)?;
+                        Statement::Call(index)
+                    }
+                },
+            };
+            block.statements.push(statement);
+        }
+    }
+
+    /// The block after `token`, which opened it.
+    fn nested_block(&mut self, token: Token<'a>) -> aoc_core::Result<Block> {
+        if self.depth == MAX_DEPTH {
+            return Err(token.error("blocks nested too deep"));
+        }
+        self.depth += 1;
+        let block = self.block(false);
+        self.depth -= 1;
+        block
+    }
+
+    fn definition(&mut self) -> aoc_core::Result<()> {
+        let name = self.next_or("a macro name")?;
+        if KEYWORDS.contains(&name.text) || name.text == "{" || name.text == "}" {
+            return Err(name.error("macro name expected"));
+        }
+        if self.macro_names.contains_key(name.text) {
+            return Err(name.error("macro already defined"));
+        }
+        self.open_brace()?;
+        let body = self.nested_block(name)?;
+        self.macro_names.insert(name.text, self.macros.len());
+        self.macros.push(body);
+        Ok(())
+    }
+}
+
+impl Script {
+    pub fn parse(text: &str) -> aoc_core::Result<Self> {
+        let last_line = text.lines().enumerate().last().unwrap_or((0, ""));
+        let mut parser = Parser {
+            tokens: tokenize(text).into_iter(),
+            last_line,
+            macro_names: HashMap::new(),
+            macros: vec![],
+            depth: 0,
+        };
+        let main = parser.block(true)?;
+        Ok(Script {
+            main,
+            macros: parser.macros,
+        })
+    }
+
+    /// How many moves the script makes, never more than `MAX_MOVES`.
+    pub fn len(&self) -> u64 {
+        self.main.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.main.len == 0
+    }
+
+    /// The moves of the script in order, with repeats and macros expanded.
+    pub fn moves(&self) -> Moves<'_> {
+        Moves {
+            script: self,
+            stack: vec![Frame {
+                statements: &self.main.statements,
+                next: 0,
+                repeats: 1,
+            }],
+        }
+    }
+}
+
+struct Frame<'a> {
+    statements: &'a [Statement],
+    next: usize,
+    /// How many more times the statements run, this time included.
+    repeats: u32,
+}
+
+/// Iterator over the moves of a script.
+pub struct Moves<'a> {
+    script: &'a Script,
+    stack: Vec<Frame<'a>>,
+}
+
+impl<'a> Iterator for Moves<'a> {
+    type Item = &'a Move;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(statement) = frame.statements.get(frame.next) else {
+                if frame.repeats > 1 {
+                    frame.repeats -= 1;
+                    frame.next = 0;
+                } else {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+            let (block, repeats) = match statement {
+                Statement::Move(m) => return Some(m),
+                Statement::Repeat(count, body) => (body, *count),
+                Statement::Call(index) => (&self.script.macros[*index], 1),
+            };
+            if block.len > 0 && repeats > 0 {
+                self.stack.push(Frame {
+                    statements: &block.statements,
+                    next: 0,
+                    repeats,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moves(text: &str) -> Vec<Move> {
+        Script::parse(text).unwrap().moves().cloned().collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("repeat 2{down 1}  # twice\n\n  forward 3");
+        let texts: Vec<&str> = tokens.iter().map(|token| token.text).collect();
+        assert_eq!(
+            texts,
+            ["repeat", "2", "{", "down", "1", "}", "forward", "3"]
+        );
+        assert_eq!((tokens[6].index, tokens[6].error("").column), (2, 3));
+    }
+
+    #[test]
+    fn test_plain_course() {
+        let script = Script::parse("forward 5\ndown 5\nback 2\nup 3").unwrap();
+        assert_eq!(script.len(), 4);
+        assert_eq!(
+            moves("forward 5\ndown 5\nback 2\nup 3"),
+            [Move::Fwd(5), Move::Down(5), Move::Back(2), Move::Up(3)]
+        );
+        assert!(Script::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_repeat_and_macros() {
+        let text = "def dive { down 2 forward 1 }
+# two dives, then back
+repeat 2 {
+    dive
+}
+back 2";
+        let expected = [
+            Move::Down(2),
+            Move::Fwd(1),
+            Move::Down(2),
+            Move::Fwd(1),
+            Move::Back(2),
+        ];
+        assert_eq!(moves(text), expected);
+        assert_eq!(
+            moves("repeat 2 { repeat 3 { up 1 } }"),
+            vec![Move::Up(1); 6]
+        );
+        assert_eq!(moves("repeat 0 { up 1 } down 1"), [Move::Down(1)]);
+        assert_eq!(
+            moves("def a { up 1 } def b { a a } b"),
+            vec![Move::Up(1); 2]
+        );
+    }
+
+    #[test]
+    fn test_large_repeats() {
+        let script = Script::parse("def nothing { } repeat 4000000000 { nothing } up 1").unwrap();
+        assert_eq!(script.moves().count(), 1);
+        let script = Script::parse("repeat 10000 { repeat 10000 { up 1 } }").unwrap();
+        assert_eq!(script.len(), MAX_MOVES);
+        assert_eq!(script.moves().nth(1000), Some(&Move::Up(1)));
+        let error = Script::parse(
+            "up 1
+repeat 10000 { repeat 10000 { up 1 } }",
+        )
+        .unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "repeat")
+        );
+        assert_eq!(error.message, "scripts make at most 100000000 moves");
+        let error = Script::parse(
+            "repeat 4000000000 {
+  repeat 4000000000 { forward 1 }
+}",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Script::parse("def a { repeat 4000000000 { forward 1 } }").unwrap_err();
+        assert_eq!(error.column, 9);
+        let error = Script::parse(
+            "def a { repeat 60000000 { up 1 } }
+a
+up 1
+a",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (4, "a"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = Script::parse("forward 5\nsideways 2").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "sideways")
+        );
+        assert_eq!(error.message, "unrecognized move");
+        let error = Script::parse("repeat 2 {\n  up x\n}").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        let error = Script::parse("repeat 2 {\n  up 1").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 7, "} expected")
+        );
+        let error = Script::parse("up 1 }").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (6, "unmatched }"));
+        let error = Script::parse("repeat 2 up 1").unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (10, "{ expected"));
+        let error = Script::parse("dive\ndef dive { down 1 }").unwrap_err();
+        assert_eq!(error.line, 1);
+        let error = Script::parse("def a { up 1 }\ndef a { up 2 }").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert!(Script::parse("def up { up 1 }").is_err());
+        assert!(Script::parse("repeat 1 { def a { up 1 } }").is_err());
+        assert!(Script::parse("def a { a }").is_err());
+        assert!(Script::parse("repeat -1 { up 1 }").is_err());
+        assert!(Script::parse("up").is_err());
+        let error = Script::parse(&"repeat 1 {".repeat(100)).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (641, "blocks nested too deep")
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt::Write;
+
+use crate::{Move, State, SubmarineModel};
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 400.0;
+const MARGIN: f64 = 40.0;
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#d62728", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b",
+];
+
+/// Traces keep every state in memory and write every one of them out, so they stop here.
+const MAX_TRACED_MOVES: usize = 100_000;
+
+/// Every state that several models go through on the same moves, starting from the surface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    names: Vec<String>,
+    moves: Vec<Move>,
+    /// The states of each model, the start included: `paths[model][step]`. A model that
+    /// overflowed stops short, at the last state before the move that overflowed it.
+    paths: Vec<Vec<State>>,
+}
+
+impl Trace {
+    /// Records the moves under every model, or fails if there are more than
+    /// `MAX_TRACED_MOVES`. A model that overflows stops there while the others go on.
+    pub fn record<'a>(
+        moves: impl IntoIterator<Item = &'a Move>,
+        models: &[Box<dyn SubmarineModel>],
+    ) -> Result<Self, String> {
+        let mut trace = Trace {
+            names: models.iter().map(|model| model.name()).collect(),
+            moves: vec![],
+            paths: vec![vec![State::default()]; models.len()],
+        };
+        for m in moves {
+            if trace.moves.len() == MAX_TRACED_MOVES {
+                return Err(format!("traces hold at most {} moves", MAX_TRACED_MOVES));
+            }
+            let steps = trace.moves.len() + 1;
+            for (path, model) in trace.paths.iter_mut().zip(models) {
+                if path.len() == steps {
+                    path.extend(model.step(*path.last().unwrap(), m));
+                }
+            }
+            trace.moves.push(*m);
+        }
+        Ok(trace)
+    }
+
+    /// The states model `index` went through, the start included, up to its overflow if any.
+    pub fn states(&self, index: usize) -> impl Iterator<Item = State> + '_ {
+        self.paths[index].iter().copied()
+    }
+
+    /// The step whose move overflowed model `index`, if one did.
+    pub fn overflow(&self, index: usize) -> Option<usize> {
+        let steps = self.paths[index].len();
+        (steps <= self.moves.len()).then_some(steps)
+    }
+
+    /// One line per step, with the move and each model's horizontal position, depth and aim.
+    /// A model reads `overflow` on the step that overflowed it, and is left blank after that.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,move");
+        for name in &self.names {
+            write!(csv, ",{0} horizontal,{0} depth,{0} aim", name).unwrap();
+        }
+        for step in 0..=self.moves.len() {
+            let m = match step {
+                0 => String::new(),
+                _ => self.moves[step - 1].to_string(),
+            };
+            write!(csv, "\n{},{}", step, m).unwrap();
+            for path in &self.paths {
+                match path.get(step) {
+                    Some(state) => {
+                        write!(csv, ",{},{},{}", state.horizontal, state.depth, state.aim)
+                    }
+                    None if step == path.len() => write!(csv, ",overflow,overflow,overflow"),
+                    None => write!(csv, ",,,"),
+                }
+                .unwrap();
+            }
+        }
+        csv
+    }
+
+    /// Depth against horizontal position, one line per model, deeper further down. A model
+    /// that overflowed ends in a dot, and its name says on which step.
+    pub fn to_svg(&self) -> String {
+        let all = self.paths.iter().flatten();
+        let (min_x, max_x) = bounds(all.clone().map(|state| state.horizontal));
+        let (min_y, max_y) = bounds(all.map(|state| state.depth));
+        let scale = |value: i64, min: i64, max: i64, size: f64| {
+            let range = (max as f64 - min as f64).max(1.0);
+            (value as f64 - min as f64) / range * (size - 2.0 * MARGIN) + MARGIN
+        };
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            WIDTH, HEIGHT
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="white"/>
+<g font-family="sans-serif" font-size="12">
+<text x="{m}" y="{top}">depth {min_y}</text>
+<text x="{m}" y="{bottom}">depth {max_y}</text>
+<text x="{right}" y="{bottom}" text-anchor="end">horizontal {min_x} to {max_x}</text>"#,
+            m = MARGIN,
+            top = MARGIN - 10.0,
+            bottom = HEIGHT - MARGIN + 20.0,
+            right = WIDTH - MARGIN,
+        )
+        .unwrap();
+        for (index, name) in self.names.iter().enumerate() {
+            let color = COLORS[index % COLORS.len()];
+            let points: Vec<(f64, f64)> = self
+                .states(index)
+                .map(|state| {
+                    let x = scale(state.horizontal, min_x, max_x, WIDTH);
+                    let y = scale(state.depth, min_y, max_y, HEIGHT);
+                    (x, y)
+                })
+                .collect();
+            let line: Vec<String> = points
+                .iter()
+                .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+                .collect();
+            writeln!(
+                svg,
+                r#"<polyline fill="none" stroke="{}" stroke-width="2" points="{}"/>"#,
+                color,
+                line.join(" ")
+            )
+            .unwrap();
+            let mut name = name.clone();
+            if let Some(step) = self.overflow(index) {
+                let (x, y) = points.last().unwrap();
+                writeln!(
+                    svg,
+                    r#"<circle cx="{:.1}" cy="{:.1}" r="4" fill="{}"/>"#,
+                    x, y, color
+                )
+                .unwrap();
+                write!(name, " (overflow at step {})", step).unwrap();
+            }
+            writeln!(
+                svg,
+                r#"<text x="{}" y="{}" fill="{}" text-anchor="end">{}</text>"#,
+                WIDTH - MARGIN,
+                MARGIN + 15.0 * index as f64,
+                color,
+                name
+            )
+            .unwrap();
+        }
+        svg.push_str("</g>\n</svg>\n");
+        svg
+    }
+}
+
+fn bounds(values: impl Iterator<Item = i64>) -> (i64, i64) {
+    values.fold((0, 0), |(min, max), value| (min.min(value), max.max(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Aim, Plain, Script};
+
+    fn sample() -> Trace {
+        let script =
+            Script::parse("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+        Trace::record(script.moves(), &[Box::new(Plain), Box::new(Aim)]).unwrap()
+    }
+
+    #[test]
+    fn test_record() {
+        let trace = sample();
+        assert_eq!(trace.states(0).count(), 7);
+        assert_eq!((trace.overflow(0), trace.overflow(1)), (None, None));
+        let depths: Vec<i64> = trace.states(1).map(|state| state.depth).collect();
+        assert_eq!(depths, [0, 0, 0, 40, 40, 40, 60]);
+        let last = trace.states(0).last().unwrap();
+        assert_eq!((last.horizontal, last.depth), (15, 10));
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = sample().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(
+            lines[0],
+            "step,move,plain horizontal,plain depth,plain aim,aim horizontal,aim depth,aim aim"
+        );
+        assert_eq!(lines[1], "0,,0,0,0,0,0,0");
+        assert_eq!(lines[3], "2,down 5,5,5,0,5,0,5");
+        assert_eq!(lines[7], "6,forward 2,15,10,0,15,60,10");
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = sample().to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        // The aim model ends deepest, at the bottom right corner.
+        assert!(svg.contains("760.0,360.0\"/>"));
+        assert!(svg.contains(">depth 60</text>"));
+        let empty = Trace::record(&[], &[Box::new(Plain)]).unwrap();
+        assert!(empty.to_svg().contains(r#"points="40.0,40.0""#));
+        assert!(!svg.contains("<circle"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Aim goes past i64 on the fourth move, plain never does.
+        let deep = [Move::Down(i32::MAX), Move::Fwd(i32::MAX)].repeat(2);
+        let trace = Trace::record(&deep, &[Box::new(Plain), Box::new(Aim)]).unwrap();
+        assert_eq!((trace.overflow(0), trace.overflow(1)), (None, Some(4)));
+        assert_eq!(trace.states(0).count(), 5);
+        assert_eq!(trace.states(1).count(), 4);
+        let csv = trace.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[3],
+            "2,forward 2147483647,2147483647,2147483647,0,2147483647,4611686014132420609,2147483647"
+        );
+        assert!(lines[5].ends_with(",overflow,overflow,overflow"));
+        assert!(lines[5].starts_with("4,forward 2147483647,4294967294,4294967294,0"));
+        let svg = trace.to_svg();
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert!(svg.contains(">aim (overflow at step 4)</text>"));
+        assert!(svg.contains(">plain</text>"));
+    }
+
+    #[test]
+    fn test_too_many_moves() {
+        let moves = vec![Move::Fwd(1); MAX_TRACED_MOVES];
+        let trace = Trace::record(&moves, &[Box::new(Plain)]).unwrap();
+        assert_eq!(trace.states(0).count(), MAX_TRACED_MOVES + 1);
+        let moves = vec![Move::Fwd(1); MAX_TRACED_MOVES + 1];
+        assert_eq!(
+            Trace::record(&moves, &[Box::new(Plain)]),
+            Err("traces hold at most 100000 moves".to_owned())
+        );
+    }
+}
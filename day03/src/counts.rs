@@ -0,0 +1,100 @@
+use crate::{Criteria, Word};
+
+/// How many words have a 1 at each position, built up one word at a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnCounts {
+    count: usize,
+    /// The widest word seen, in digits.
+    width: usize,
+    /// `ones[order]` words have bit `order` set. Missing positions have none.
+    ones: Vec<usize>,
+}
+
+impl ColumnCounts {
+    /// Counts a word that was written with `width` digits.
+    pub fn add(&mut self, word: &Word, width: usize) {
+        self.count += 1;
+        self.width = self.width.max(width);
+        for order in word.ones() {
+            if order >= self.ones.len() {
+                self.ones.resize(order + 1, 0);
+            }
+            self.ones[order] += 1;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn ones(&self, shift: usize) -> usize {
+        self.ones.get(shift).copied().unwrap_or(0)
+    }
+
+    /// The gamma and epsilon rates: the most common bit at every position, and the others.
+    pub fn rates(&self) -> (Word, Word) {
+        let mut gamma_rate = Word::default();
+        for order in 0..self.width {
+            if Criteria::OXYGEN.bit(self.ones(order), self.count) {
+                gamma_rate.set(order);
+            }
+        }
+        let epsilon_rate = gamma_rate.complement(self.width);
+        (gamma_rate, epsilon_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_words;
+
+    fn counts(values: &[u128], width: usize) -> ColumnCounts {
+        let mut counts = ColumnCounts::default();
+        for &value in values {
+            counts.add(&Word::from(value), width);
+        }
+        counts
+    }
+
+    #[test]
+    fn test_ones() {
+        let counts = counts(&[0b1000, 0b0111, 0b1001], 4);
+        assert_eq!(counts.ones(3), 2);
+        assert_eq!(counts.ones(100), 0);
+        assert_eq!((counts.count(), counts.width()), (3, 4));
+    }
+
+    #[test]
+    fn test_most_common() {
+        let (gamma_rate, epsilon_rate) = counts(&[0b1000, 0b0111, 0b1001, 0b0011], 4).rates();
+        assert_eq!(gamma_rate, Word::from(0b1011));
+        assert_eq!(epsilon_rate, Word::from(0b0100));
+        let counts = counts(
+            &[
+                0b11110, 0b10110, 0b10111, 0b10101, 0b11100, 0b10000, 0b11001,
+            ],
+            5,
+        );
+        assert_eq!(counts.ones(3), 3);
+        assert!(!counts.rates().0.bit(3));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut counts = ColumnCounts::default();
+        for word in read_words("00100\n11110\n10110".as_bytes()) {
+            let (word, width) = word.unwrap();
+            counts.add(&word, width);
+        }
+        assert_eq!(counts.rates(), (Word::from(0b10110), Word::from(0b01001)));
+        let mut words = read_words("0101\n0121".as_bytes());
+        assert!(words.next().unwrap().is_ok());
+        let error = words.next().unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
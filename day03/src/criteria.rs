@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+
+/// Which of the two bits a rating keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commonness {
+    Most,
+    Least,
+}
+
+/// Which end of the words a rating starts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+/// How a rating narrows the values down to one: bit after bit, in `order`, only the values
+/// with the `keep` common bit stay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Criteria {
+    pub keep: Commonness,
+    /// The bit kept when there are as many of each.
+    pub tie: bool,
+    pub order: ScanOrder,
+}
+
+impl Criteria {
+    pub const OXYGEN: Criteria = Criteria {
+        keep: Commonness::Most,
+        tie: true,
+        order: ScanOrder::MsbFirst,
+    };
+
+    pub const CO2: Criteria = Criteria {
+        keep: Commonness::Least,
+        tie: false,
+        order: ScanOrder::MsbFirst,
+    };
+
+    /// The bit to keep when `ones` of `count` values have a 1.
+    pub fn bit(&self, ones: usize, count: usize) -> bool {
+        match ones.cmp(&(count - ones)) {
+            Ordering::Equal => self.tie,
+            ordering => (ordering == Ordering::Greater) == (self.keep == Commonness::Most),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit() {
+        assert!(Criteria::OXYGEN.bit(7, 12));
+        assert!(!Criteria::OXYGEN.bit(5, 12));
+        assert!(Criteria::OXYGEN.bit(6, 12));
+        assert!(!Criteria::CO2.bit(7, 12));
+        assert!(Criteria::CO2.bit(5, 12));
+        assert!(!Criteria::CO2.bit(6, 12));
+        let least_ones = Criteria {
+            tie: true,
+            ..Criteria::CO2
+        };
+        assert!(least_ones.bit(1, 2));
+        assert!(Criteria::OXYGEN.bit(0, 0));
+    }
+}
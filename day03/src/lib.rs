@@ -0,0 +1,310 @@
+use aoc_core::{Answer, ParseError, Solution};
+use std::io::BufRead;
+
+mod counts;
+mod criteria;
+mod word;
+
+pub use counts::ColumnCounts;
+pub use criteria::{Commonness, Criteria, ScanOrder};
+pub use word::Word;
+
+impl Solution for Values {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        let lines: Vec<&str> = input.lines().collect();
+        Values::parse(&lines)
+    }
+
+    fn part1(&self) -> Answer {
+        let (gamma_rate, epsilon_rate) = part1(self);
+        gamma_rate.product(&epsilon_rate).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (oxy, co2) = part2(self);
+        oxy.product(&co2).into()
+    }
+}
+
+pub struct Values {
+    /// Every value, sorted, so that values with the same leading bits are next to each other.
+    values: Vec<Word>,
+    counts: ColumnCounts,
+}
+
+impl Values {
+    pub fn parse(lines: &[&str]) -> aoc_core::Result<Self> {
+        let mut counts = ColumnCounts::default();
+        let mut values = lines
+            .iter()
+            .enumerate()
+            .map(|(index, &line)| {
+                let value = parse_word(index, line)?;
+                counts.add(&value, line.len());
+                Ok(value)
+            })
+            .collect::<aoc_core::Result<Vec<Word>>>()?;
+        if values.is_empty() {
+            return Err(ParseError::new(1, 1, "", "binary value expected"));
+        }
+        values.sort_unstable();
+        Ok(Self { values, counts })
+    }
+}
+
+/// Line `index` of the report, read as a binary number.
+fn parse_word(index: usize, line: &str) -> aoc_core::Result<Word> {
+    let mut value = Word::default();
+    for (order, char) in line.char_indices() {
+        match char {
+            '0' => {}
+            '1' => value.set(line.len() - order - 1),
+            _ => {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    &line[order..order + char.len_utf8()],
+                    "binary digit expected",
+                ))
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Words read one line at a time, each with the number of digits it was written with, so that
+/// reports too large to keep can be counted as they are read.
+pub fn read_words(reader: impl BufRead) -> impl Iterator<Item = aoc_core::Result<(Word, usize)>> {
+    reader.lines().enumerate().map(|(index, line)| {
+        let line = line.map_err(|e| ParseError::new(index + 1, 1, "", e.to_string()))?;
+        Ok((parse_word(index, &line)?, line.len()))
+    })
+}
+
+pub fn part1(values: &Values) -> (Word, Word) {
+    values.counts.rates()
+}
+
+pub fn part2(values: &Values) -> (Word, Word) {
+    (
+        life_support_rating(values, &Criteria::OXYGEN),
+        life_support_rating(values, &Criteria::CO2),
+    )
+}
+
+/// The value left once `criteria` have filtered out all the others.
+///
+/// In sorted values, the ones sharing the bits looked at so far make up a range, and within it
+/// the values with a 0 at the next bit come before those with a 1. So each round only has to
+/// find where the 1s start, and the values never have to be copied. Scanning from the least
+/// significant bit works the same way on the values with their bits reversed.
+pub fn life_support_rating(bit_stats: &Values, criteria: &Criteria) -> Word {
+    let width = bit_stats.counts.width();
+    let reversed: Vec<Word>;
+    let mut values = match criteria.order {
+        ScanOrder::MsbFirst => &bit_stats.values[..],
+        ScanOrder::LsbFirst => {
+            let mut words: Vec<Word> = bit_stats
+                .values
+                .iter()
+                .map(|value| value.reversed(width))
+                .collect();
+            words.sort_unstable();
+            reversed = words;
+            &reversed[..]
+        }
+    };
+    let mut order = 0;
+    while values.len() > 1 && order < width {
+        let shift = width - order - 1;
+        let split = values.partition_point(|v| !v.bit(shift));
+        let keep = criteria.bit(values.len() - split, values.len());
+        // When every value has the same bit, the other bit is not "least common" but absent.
+        if keep && split < values.len() {
+            values = &values[split..];
+        } else if !keep && split > 0 {
+            values = &values[..split];
+        }
+        order += 1;
+    }
+    match criteria.order {
+        ScanOrder::MsbFirst => values[0].clone(),
+        ScanOrder::LsbFirst => values[0].reversed(width),
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*, string::string_regex};
+    const DATA: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn part1_test() {
+        let data: Vec<&str> = DATA.lines().collect();
+        let bit_stats = Values::parse(&data).unwrap();
+        let (gamma_rate, epsilon_rate) = part1(&bit_stats);
+        assert_eq!(gamma_rate, Word::from(22));
+        assert_eq!(epsilon_rate, Word::from(9));
+        assert_eq!(bit_stats.part1(), Answer::from(198));
+    }
+
+    #[test]
+    fn check_reversed() {
+        let a = "11110";
+        let mut reversed = a.chars().rev().enumerate();
+        assert_eq!(reversed.next(), Some((0, '0')));
+        assert_eq!(reversed.next(), Some((1, '1')));
+        assert_eq!(reversed.next(), Some((2, '1')));
+        assert_eq!(reversed.next(), Some((3, '1')));
+        assert_eq!(reversed.next(), Some((4, '1')));
+        assert_eq!(reversed.next(), None);
+    }
+    #[test]
+    fn part2_test() {
+        let data: Vec<&str> = DATA.lines().collect();
+        let bit_stats = Values::parse(&data).unwrap();
+        let (oxy, co2) = part2(&bit_stats);
+
+        assert_eq!(oxy, Word::from(23));
+        assert_eq!(co2, Word::from(10));
+    }
+    #[test]
+    fn test_criteria() {
+        let data: Vec<&str> = DATA.lines().collect();
+        let values = Values::parse(&data).unwrap();
+        let rating = |keep, tie, order| {
+            let criteria = Criteria { keep, tie, order };
+            life_support_rating(&values, &criteria).to_u128().unwrap()
+        };
+        use Commonness::*;
+        use ScanOrder::*;
+        assert_eq!(rating(Most, true, MsbFirst), 23);
+        assert_eq!(rating(Least, false, MsbFirst), 10);
+        assert_eq!(rating(Most, true, LsbFirst), 30);
+        assert_eq!(rating(Least, false, LsbFirst), 25);
+        assert_eq!(rating(Most, false, MsbFirst), 22);
+        assert_eq!(rating(Least, true, MsbFirst), 15);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = Values::parse(&["00100", "01201"]).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "2");
+    }
+    #[test]
+    fn test_wide_values() {
+        let high = format!("1{}", "0".repeat(99));
+        let values = Values::parse(&[&high, &high, &"0".repeat(100)]).unwrap();
+        let (gamma_rate, epsilon_rate) = part1(&values);
+        let mut expected = Word::default();
+        expected.set(99);
+        assert_eq!(epsilon_rate, expected.complement(100));
+        assert_eq!(gamma_rate, expected);
+        assert_eq!(
+            values.part1(),
+            Answer::from("401734511064747568885490523084656825330436633744949857222656")
+        );
+        assert_eq!(part2(&values), (expected, Word::default()));
+        let values = Values::parse(&[&"1".repeat(128), "0"]).unwrap();
+        assert_eq!(values.part2(), Answer::from(0));
+    }
+    #[test]
+    fn test_duplicates() {
+        let values = Values::parse(&["101", "101", "010"]).unwrap();
+        assert_eq!(part2(&values), (Word::from(0b101), Word::from(0b010)));
+        let values = Values::parse(&["1", "1"]).unwrap();
+        assert_eq!(part2(&values), (Word::from(1), Word::from(1)));
+        assert!(Values::parse(&[]).is_err());
+        let values = Values::parse(&["1".repeat(32).as_str(), "0"]).unwrap();
+        assert_eq!(values.part1(), Answer::from(0u64));
+    }
+
+    /// The rating the slow way, filtering a copy of the values round after round.
+    fn filtered_rating(values: &Values, criteria: &Criteria) -> Word {
+        let width = values.counts.width();
+        let mut values = values.values.clone();
+        for round in 0..width {
+            if values.len() == 1 {
+                break;
+            }
+            let shift = match criteria.order {
+                ScanOrder::MsbFirst => width - round - 1,
+                ScanOrder::LsbFirst => round,
+            };
+            let ones = values.iter().filter(|v| v.bit(shift)).count();
+            let keep = criteria.bit(ones, values.len());
+            if values.iter().any(|v| v.bit(shift) == keep) {
+                values.retain(|v| v.bit(shift) == keep);
+            }
+        }
+        values.swap_remove(0)
+    }
+
+    /// Lines of one width, as `(width, lines)`.
+    fn report() -> impl Strategy<Value = (usize, Vec<String>)> {
+        (1..=130usize).prop_flat_map(|width| {
+            let line = string_regex(&format!("[01]{{{}}}", width)).unwrap();
+            (Just(width), vec(line, 1..100))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(values) = Values::from_input(&text) {
+                values.part1();
+                values.part2();
+            }
+        }
+
+        #[test]
+        fn rates_are_complements((width, lines) in report()) {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let values = Values::parse(&lines).unwrap();
+            let (gamma_rate, epsilon_rate) = part1(&values);
+            for order in 0..width + 64 {
+                prop_assert_eq!(gamma_rate.bit(order) != epsilon_rate.bit(order), order < width);
+            }
+        }
+
+        #[test]
+        fn ratings_are_values((_, lines) in report()) {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let values = Values::parse(&lines).unwrap();
+            let (oxy, co2) = part2(&values);
+            prop_assert!(values.values.contains(&oxy));
+            prop_assert!(values.values.contains(&co2));
+        }
+
+        #[test]
+        fn any_rating_is_a_value(
+            (_, lines) in report(),
+            most in any::<bool>(),
+            tie in any::<bool>(),
+            msb in any::<bool>(),
+        ) {
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let values = Values::parse(&lines).unwrap();
+            let criteria = Criteria {
+                keep: if most { Commonness::Most } else { Commonness::Least },
+                tie,
+                order: if msb { ScanOrder::MsbFirst } else { ScanOrder::LsbFirst },
+            };
+            let rating = life_support_rating(&values, &criteria);
+            prop_assert!(values.values.contains(&rating));
+            prop_assert_eq!(rating, filtered_rating(&values, &criteria));
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use aoc_core::Answer;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+const LIMB_BITS: usize = u64::BITS as usize;
+
+/// An unsigned number of any width. Bit 0 is the last digit of a diagnostic line.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Word {
+    /// 64 bits at a time, the lowest first, with no zero limbs at the end so that equal numbers
+    /// are stored the same way.
+    limbs: Vec<u64>,
+}
+
+impl Word {
+    pub fn bit(&self, order: usize) -> bool {
+        self.limbs
+            .get(order / LIMB_BITS)
+            .is_some_and(|limb| (limb >> (order % LIMB_BITS)) & 1 == 1)
+    }
+
+    pub fn set(&mut self, order: usize) {
+        let index = order / LIMB_BITS;
+        if index >= self.limbs.len() {
+            self.limbs.resize(index + 1, 0);
+        }
+        self.limbs[index] |= 1 << (order % LIMB_BITS);
+    }
+
+    /// The positions of the 1 bits, the lowest first.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.limbs.iter().enumerate().flat_map(|(index, &limb)| {
+            let mut rest = limb;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let order = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    index * LIMB_BITS + order
+                })
+            })
+        })
+    }
+
+    /// The lowest `width` bits in the opposite order.
+    pub fn reversed(&self, width: usize) -> Word {
+        let mut reversed = Word::default();
+        for order in self.ones().take_while(|&order| order < width) {
+            reversed.set(width - order - 1);
+        }
+        reversed
+    }
+
+    /// The word with its lowest `width` bits flipped and the others cleared.
+    pub fn complement(&self, width: usize) -> Word {
+        let mut limbs: Vec<u64> = (0..width.div_ceil(LIMB_BITS))
+            .map(|index| !self.limbs.get(index).copied().unwrap_or(0))
+            .collect();
+        if !width.is_multiple_of(LIMB_BITS) {
+            if let Some(last) = limbs.last_mut() {
+                *last &= (1 << (width % LIMB_BITS)) - 1;
+            }
+        }
+        Word::from_limbs(limbs)
+    }
+
+    pub fn product(&self, other: &Word) -> Word {
+        let mut limbs = vec![0; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let sum = a as u128 * b as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = sum as u64;
+                carry = sum >> LIMB_BITS;
+            }
+            limbs[i + other.limbs.len()] = carry as u64;
+        }
+        Word::from_limbs(limbs)
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(low as u128),
+            [low, high] => Some((high as u128) << LIMB_BITS | low as u128),
+            _ => None,
+        }
+    }
+
+    fn from_limbs(mut limbs: Vec<u64>) -> Word {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Word { limbs }
+    }
+}
+
+impl From<u128> for Word {
+    fn from(value: u128) -> Self {
+        Word::from_limbs(vec![value as u64, (value >> LIMB_BITS) as u64])
+    }
+}
+
+impl Ord for Word {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for Word {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// In decimal, like the integers it stands for.
+impl Display for Word {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        // Divide by 10^19 until nothing is left, keeping the remainders as 19 digit chunks.
+        let mut limbs = self.limbs.clone();
+        let mut chunks = vec![];
+        while !limbs.is_empty() {
+            let mut remainder = 0;
+            for limb in limbs.iter_mut().rev() {
+                let value = (remainder as u128) << LIMB_BITS | *limb as u128;
+                *limb = (value / CHUNK as u128) as u64;
+                remainder = (value % CHUNK as u128) as u64;
+            }
+            chunks.push(remainder);
+            limbs = Word::from_limbs(limbs).limbs;
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:019}", chunk));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+/// An integer answer while it fits, and the digits after that.
+impl From<Word> for Answer {
+    fn from(word: Word) -> Self {
+        match word.to_u128().and_then(|value| i128::try_from(value).ok()) {
+            Some(value) => Answer::Int(value),
+            None => Answer::Text(word.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_bit() {
+        let word = Word::from(0b1000);
+        assert!(word.bit(3));
+        assert!(!word.bit(4));
+        assert!(!word.bit(2));
+        assert!(!word.bit(1000));
+        let word = Word::from(0b0100);
+        assert!(word.bit(2));
+        assert!(!word.bit(3) && !word.bit(1) && !word.bit(0));
+        let mut word = Word::default();
+        word.set(130);
+        word.set(2);
+        assert!(word.bit(130) && word.bit(2) && !word.bit(129));
+        assert_eq!(word.to_u128(), None);
+    }
+
+    #[test]
+    fn test_ones() {
+        let mut word = Word::from(0b1011);
+        word.set(64);
+        word.set(200);
+        assert_eq!(word.ones().collect::<Vec<usize>>(), [0, 1, 3, 64, 200]);
+        assert_eq!(Word::default().ones().count(), 0);
+        assert_eq!(Word::from(0b00111).reversed(5), Word::from(0b11100));
+        assert_eq!(word.reversed(4), Word::from(0b1101));
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(Word::from(0b10110).complement(5), Word::from(0b01001));
+        assert_eq!(Word::from(u64::MAX as u128).complement(64), Word::default());
+        let mut high = Word::default();
+        high.set(99);
+        assert_eq!(high.complement(100), Word::from((1 << 99) - 1));
+        assert_eq!(Word::default().complement(0), Word::default());
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        let mut high = Word::default();
+        high.set(99);
+        let product = high.product(&high.complement(100));
+        assert_eq!(
+            product.to_string(),
+            "401734511064747568885490523084656825330436633744949857222656"
+        );
+        assert!(matches!(Answer::from(product), Answer::Text(_)));
+        assert_eq!(Answer::from(Word::from(198)), Answer::Int(198));
+        assert_eq!(Word::default().to_string(), "0");
+        assert_eq!(format!("{:>4}", Word::from(42)), "  42");
+    }
+
+    proptest! {
+        #[test]
+        fn words_act_like_integers(a in any::<u128>(), b in any::<u128>()) {
+            let (x, y) = (Word::from(a), Word::from(b));
+            prop_assert_eq!(x.to_u128(), Some(a));
+            prop_assert_eq!(x.to_string(), a.to_string());
+            prop_assert_eq!(x.cmp(&y), a.cmp(&b));
+            let (a, b) = (a as u64, b as u64);
+            prop_assert_eq!(
+                Word::from(a as u128).product(&Word::from(b as u128)),
+                Word::from(a as u128 * b as u128)
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Something that happened during a game. Boards are numbered from 0 in input order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// The number drawn on `turn`, counting from 1.
+    Draw { turn: usize, number: u32 },
+    /// A board that won with the number drawn on `turn`. The first board to win has rank 1, and
+    /// boards winning on the same draw are ranked in input order.
+    Win {
+        turn: usize,
+        board: usize,
+        rank: usize,
+        score: u64,
+    },
+}
+
+/// Every event of a game, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Log {
+    pub events: Vec<Event>,
+}
+
+impl Log {
+    /// The boards in the order they won. Boards that never won are left out.
+    pub fn ranking(&self) -> Vec<usize> {
+        self.events
+            .iter()
+            .filter_map(|event| match *event {
+                Event::Win { board, .. } => Some(board),
+                Event::Draw { .. } => None,
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("logs only hold numbers")
+    }
+}
+
+impl Display for Log {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, event) in self.events.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Draw { turn, number } => write!(f, "turn {}: {}", turn, number),
+            Event::Win {
+                board, rank, score, ..
+            } => write!(f, "  board {} wins, rank {}, score {}", board, rank, score),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log() -> Log {
+        Log {
+            events: vec![
+                Event::Draw { turn: 1, number: 7 },
+                Event::Draw { turn: 2, number: 4 },
+                Event::Win {
+                    turn: 2,
+                    board: 1,
+                    rank: 1,
+                    score: 40,
+                },
+                Event::Win {
+                    turn: 2,
+                    board: 0,
+                    rank: 2,
+                    score: 12,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_ranking() {
+        assert_eq!(log().ranking(), [1, 0]);
+        assert!(Log::default().ranking().is_empty());
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value = serde_json::from_str(&log().to_json()).unwrap();
+        assert_eq!(json["events"][0]["event"], "draw");
+        assert_eq!(json["events"][1]["number"], 4);
+        assert_eq!(json["events"][2]["event"], "win");
+        assert_eq!(json["events"][3]["score"], 12);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            log().to_string(),
+            "turn 1: 7\nturn 2: 4\n  board 1 wins, rank 1, score 40\n  board 0 wins, rank 2, score 12"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+/// The size of a random game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shape {
+    pub boards: usize,
+    pub width: usize,
+    pub height: usize,
+    /// Every number in `0..numbers` is drawn once. There are never fewer than a board holds.
+    pub numbers: u32,
+}
+
+/// The size of the puzzle inputs.
+impl Default for Shape {
+    fn default() -> Self {
+        Shape {
+            boards: 100,
+            width: 5,
+            height: 5,
+            numbers: 100,
+        }
+    }
+}
+
+/// A valid puzzle input: all the numbers in a random order, then boards of distinct numbers.
+pub fn random_input(shape: &Shape, rng: &mut impl Rng) -> String {
+    let cells = shape.width * shape.height;
+    let count = (shape.numbers as usize).max(cells);
+    let mut numbers: Vec<usize> = (0..count).collect();
+    numbers.shuffle(rng);
+    let numbers: Vec<String> = numbers.iter().map(usize::to_string).collect();
+    let mut text = numbers.join(",");
+    text.push('\n');
+    for _ in 0..shape.boards {
+        let board = index::sample(rng, count, cells).into_vec();
+        for row in board.chunks(shape.width.max(1)) {
+            let row: Vec<String> = row.iter().map(|v| format!("{:2}", v)).collect();
+            text.push('\n');
+            text.push_str(&row.join(" "));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_random_input() {
+        let shape = Shape {
+            boards: 20,
+            width: 4,
+            height: 3,
+            numbers: 5,
+        };
+        let text = random_input(&shape, &mut ChaCha8Rng::seed_from_u64(1));
+        assert_eq!(
+            text,
+            random_input(&shape, &mut ChaCha8Rng::seed_from_u64(1))
+        );
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0].split(',').count(), 12);
+        let mut game = Game::parse(&lines).unwrap();
+        assert_eq!(game.log().ranking().len(), 20);
+        let text = random_input(&Shape::default(), &mut ChaCha8Rng::seed_from_u64(2));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(Game::parse(&lines).unwrap().boards.len(), 100);
+    }
+}
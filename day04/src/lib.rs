@@ -0,0 +1,749 @@
+use aoc_core::{expect_part, parse_at, Answer, ParseError, Solution};
+use aoc_grid::{Grid, Pos};
+use colored::Colorize;
+use std::collections::HashSet;
+
+mod events;
+mod generate;
+mod pattern;
+mod simulate;
+mod tally;
+
+pub use events::{Event, Log};
+pub use generate::{random_input, Shape};
+pub use pattern::Pattern;
+pub use simulate::{BoardOdds, Odds};
+use tally::Tally;
+
+pub struct Bingo {
+    game: Game,
+}
+
+impl Solution for Bingo {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        let lines: Vec<&str> = input.lines().collect();
+        Ok(Bingo {
+            game: Game::parse(&lines)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.game.clone().part1().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.game.clone().part2().into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    cells: Grid<u32>,
+}
+
+impl Board {
+    /// A board as big as its lines say. Blank lines may follow it, but anything after them is
+    /// an error. `first_line` is the index of `lines[0]` in the whole input, for error positions.
+    pub fn parse(first_line: usize, lines: &[&str]) -> aoc_core::Result<Self> {
+        let rows = lines.iter().take_while(|line| !line.is_empty()).count();
+        let width = lines
+            .first()
+            .map_or(0, |line| line.split_whitespace().count());
+        if width == 0 {
+            let line = lines.first().copied().unwrap_or_default();
+            return Err(ParseError::line(first_line, line, "board numbers expected"));
+        }
+        Board::parse_sized(first_line, lines, width, rows)
+    }
+
+    /// A board that should have `height` rows of `width` cells.
+    fn parse_sized(
+        first_line: usize,
+        lines: &[&str],
+        width: usize,
+        height: usize,
+    ) -> aoc_core::Result<Self> {
+        let mut rows = vec![];
+        for (y, &line) in lines.iter().enumerate() {
+            let index = first_line + y;
+            if !line.is_empty() {
+                let values: Vec<&str> = line.split_whitespace().collect();
+                if y >= height {
+                    return Err(ParseError::line(
+                        index,
+                        line,
+                        format!("boards should have {} rows", height),
+                    ));
+                }
+                if values.len() != width {
+                    return Err(ParseError::line(
+                        index,
+                        line,
+                        format!("board rows should have {} cells", width),
+                    ));
+                }
+                let row = values
+                    .into_iter()
+                    .map(|value| parse_at(index, line, value))
+                    .collect::<aoc_core::Result<Vec<u32>>>()?;
+                rows.push(row);
+            } else if y < height {
+                return Err(ParseError::new(
+                    index + 1,
+                    1,
+                    line,
+                    format!("boards should have {} rows", height),
+                ));
+            }
+        }
+        if rows.len() < height {
+            return Err(ParseError::new(
+                first_line + lines.len() + 1,
+                1,
+                "",
+                format!("boards should have {} rows", height),
+            ));
+        }
+        Ok(Self {
+            cells: Grid::from_rows(rows),
+        })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> i32 {
+        self.cells.height()
+    }
+
+    #[cfg(test)]
+    fn value(&self, pos: Pos) -> u32 {
+        self.cells[pos]
+    }
+
+    #[cfg(test)]
+    fn column(&self, index: i32) -> impl Iterator<Item = u32> + '_ {
+        self.cells.column(index).copied()
+    }
+
+    fn row(&self, index: i32) -> impl Iterator<Item = u32> + '_ {
+        self.cells.row(index).copied()
+    }
+
+    /// The rows of the board, one per line, with the marked numbers highlighted.
+    pub fn render(&self, marked: &HashSet<u32>) -> String {
+        let mut text = String::new();
+        for index in 0..self.height() {
+            for item in self.row(index) {
+                let cell = format!("{:2}", item);
+                if marked.contains(&item) {
+                    text += &format!("{} ", cell.bold().green());
+                } else {
+                    text += &format!("{} ", cell);
+                }
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    boards: Vec<Board>,
+    numbers: Vec<u32>,
+    /// Where each number is, and how close each board is to winning.
+    tally: Tally,
+    marked: HashSet<u32>,
+    number_index: usize,
+    /// Which boards have won so far, and how many.
+    won: Vec<bool>,
+    winners: usize,
+}
+
+impl Game {
+    /// Boards are separated by blank lines, and all have the size of the first one.
+    pub fn parse(lines: &[&str]) -> aoc_core::Result<Self> {
+        let first = expect_part(0, "", lines.first().copied(), "the drawn numbers")?;
+        let numbers = first
+            .split(',')
+            .map(|str| parse_at(0, first, str))
+            .collect::<aoc_core::Result<_>>()?;
+        let mut boards: Vec<Board> = vec![];
+        let mut index = 1;
+        while index < lines.len() {
+            if lines[index].is_empty() {
+                index += 1;
+                continue;
+            }
+            let rows = lines[index..]
+                .iter()
+                .take_while(|line| !line.is_empty())
+                .count();
+            let board = match boards.first() {
+                Some(first) => Board::parse_sized(
+                    index,
+                    &lines[index..index + rows],
+                    first.width() as usize,
+                    first.height() as usize,
+                )?,
+                None => Board::parse(index, &lines[index..index + rows])?,
+            };
+            boards.push(board);
+            index += rows;
+        }
+        let mut game = Self {
+            won: vec![false; boards.len()],
+            boards,
+            numbers,
+            tally: Tally::default(),
+            marked: HashSet::new(),
+            number_index: 0,
+            winners: 0,
+        };
+        game.set_patterns(&Pattern::STANDARD);
+        Ok(game)
+    }
+
+    /// The same game, won by any of `patterns` instead of rows and columns.
+    pub fn with_patterns(mut self, patterns: &[Pattern]) -> Self {
+        self.set_patterns(patterns);
+        self
+    }
+
+    fn set_patterns(&mut self, patterns: &[Pattern]) {
+        let (width, height) = self
+            .boards
+            .first()
+            .map_or((0, 0), |board| (board.width(), board.height()));
+        let groups: Vec<Vec<Pos>> = patterns
+            .iter()
+            .flat_map(|pattern| pattern.groups(width, height))
+            .collect();
+        self.tally = Tally::new(&self.boards, &groups);
+        for &number in &self.marked {
+            self.tally.mark(number);
+        }
+    }
+
+    /// Draws the next number and notes the boards it makes win, or returns None once all
+    /// numbers are drawn.
+    pub fn draw(&mut self) -> Option<Vec<Event>> {
+        let &number = self.numbers.get(self.number_index)?;
+        self.number_index += 1;
+        let turn = self.number_index;
+        let mut events = vec![Event::Draw { turn, number }];
+        // A number drawn again marks nothing new.
+        if self.marked.insert(number) {
+            for board in self.tally.mark(number) {
+                if !self.won[board] {
+                    self.won[board] = true;
+                    self.winners += 1;
+                    events.push(Event::Win {
+                        turn,
+                        board,
+                        rank: self.winners,
+                        score: self.score(board),
+                    });
+                }
+            }
+        }
+        Some(events)
+    }
+
+    /// What happens as the rest of the numbers are drawn, one draw at a time.
+    pub fn events(&mut self) -> impl Iterator<Item = Event> + '_ {
+        std::iter::from_fn(move || self.draw()).flatten()
+    }
+
+    /// Starts the game over with `numbers` to draw.
+    pub fn restart(&mut self, numbers: Vec<u32>) {
+        self.numbers = numbers;
+        self.number_index = 0;
+        self.marked.clear();
+        self.won.fill(false);
+        self.winners = 0;
+        self.tally.clear();
+    }
+
+    /// Every event of the rest of the game.
+    pub fn log(&mut self) -> Log {
+        Log {
+            events: self.events().collect(),
+        }
+    }
+
+    /// Board `board` as it stands, with the numbers drawn so far highlighted.
+    pub fn render(&self, board: usize) -> String {
+        self.boards[board].render(&self.marked)
+    }
+
+    fn last_number(&self) -> u32 {
+        // panics on first turn
+        self.numbers[self.number_index - 1]
+    }
+
+    fn score(&self, board: usize) -> u64 {
+        self.tally.unmarked_sum(board) * self.last_number() as u64
+    }
+
+    /// The score of the first board to win, if any board wins.
+    pub fn part1(&mut self) -> Option<u64> {
+        self.events().find_map(|event| match event {
+            Event::Win { score, .. } => Some(score),
+            Event::Draw { .. } => None,
+        })
+    }
+
+    /// The score of the last board to win, if every board wins.
+    pub fn part2(&mut self) -> Option<u64> {
+        let count = self.boards.len();
+        self.events().find_map(|event| match event {
+            Event::Win { rank, score, .. } if rank == count => Some(score),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+    use std::sync::Mutex;
+    const DATA: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    const NUMBERS: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1";
+    const BOARD: &str = "22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+";
+    /// The slow way to play, scanning the whole board on every draw, to check `Tally` against.
+    impl Board {
+        fn unmarked_sum(&self, marked: &HashSet<u32>) -> u64 {
+            self.cells
+                .values()
+                .filter(|v| !marked.contains(v))
+                .map(|&v| v as u64)
+                .sum()
+        }
+
+        /// Whether a whole row or column is marked.
+        fn is_winning(&self, marked: &HashSet<u32>) -> bool {
+            (0..self.width()).any(|index| iterator_is_winning(self.column(index), marked))
+                || (0..self.height()).any(|index| iterator_is_winning(self.row(index), marked))
+        }
+
+        /// Whether every cell of one of `groups` is marked.
+        fn wins(&self, groups: &[Vec<Pos>], marked: &HashSet<u32>) -> bool {
+            groups
+                .iter()
+                .any(|group| iterator_is_winning(group.iter().map(|&pos| self.cells[pos]), marked))
+        }
+    }
+
+    fn iterator_is_winning(mut iterator: impl Iterator<Item = u32>, marked: &HashSet<u32>) -> bool {
+        iterator.all(|v| marked.contains(&v))
+    }
+
+    /// Colour is switched on and off for the whole process, so tests that switch it take turns.
+    static COLOR: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_parse_numbers() {
+        let numbers: Vec<u32> = NUMBERS
+            .split(',')
+            .map(|str| str.parse::<u32>().expect("every number should be a u32."))
+            .collect();
+        assert_eq!(numbers[0], 7);
+        assert_eq!(numbers[numbers.len() - 1], 1);
+    }
+
+    #[test]
+    fn test_parse_board() {
+        let lines: Vec<&str> = BOARD.lines().collect();
+        let board = Board::parse(0, &lines).unwrap();
+        assert_eq!(board.value(Pos::new(0, 0)), 22);
+        assert_eq!(board.value(Pos::new(4, 0)), 0);
+        assert_eq!(board.value(Pos::new(4, 4)), 19);
+        assert_eq!(board.value(Pos::new(0, 4)), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Game::parse(&["7,4,x", ""]).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        let error = Board::parse(2, &["22 13 17 11  0", " 8  2 23  4"]).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (4, " 8  2 23  4"));
+        let error = Board::parse(2, &["22 13 17 11  0", " 8  2 23  4 -1"]).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 13));
+        assert!(Board::parse(0, &["", "1 2"]).is_err());
+        assert!(Game::parse(&[]).is_err());
+        // Every board has the size of the first one.
+        let error = Game::parse(&["1", "", "1 2", "3 4", "", "1 2", ""]).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (7, "boards should have 2 rows")
+        );
+        let error = Game::parse(&["1", "", "1 2", "", "1 2", "3 4"]).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (6, "3 4"));
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let game = Game::parse(&lines).unwrap();
+        assert_eq!(game.boards.len(), 3);
+        println!("{:?}", game);
+        // Assert!(false);
+    }
+
+    #[test]
+    fn test_board_sizes() {
+        let board = Board::parse(0, &["1 2 3", "4 5 6", ""]).unwrap();
+        assert_eq!((board.width(), board.height()), (3, 2));
+        let board = Board::parse(0, &["1 2", "", ""]).unwrap();
+        assert_eq!((board.width(), board.height()), (2, 1));
+        let error = Board::parse(4, &["1 2", "", "3 4"]).unwrap_err();
+        assert_eq!(
+            (error.line, error.text.as_str(), error.message.as_str()),
+            (7, "3 4", "boards should have 1 rows")
+        );
+        let lines = ["4,5,6,1,2", "", "1 2 3", "4 5 6", "", "", "7 8 9", "1 4 2"];
+        let mut game = Game::parse(&lines).unwrap();
+        assert_eq!(game.boards.len(), 2);
+        assert_eq!(game.clone().part1(), Some((1 + 2 + 3) * 6));
+        assert_eq!(game.part2(), Some((7 + 8 + 9) * 2));
+    }
+
+    #[test]
+    fn test_patterns() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let game = Game::parse(&lines).unwrap();
+        let play = |patterns: &[Pattern]| {
+            let game = game.clone().with_patterns(patterns);
+            (game.clone().part1(), game.clone().part2())
+        };
+        assert_eq!(play(&Pattern::STANDARD), (Some(4512), Some(1924)));
+        let diagonals = [Pattern::Rows, Pattern::Columns, Pattern::Diagonals];
+        assert_eq!(play(&diagonals), (Some(494), Some(1924)));
+        assert_eq!(play(&[Pattern::Diagonals]), (Some(494), Some(76)));
+        assert_eq!(play(&[Pattern::Corners]), (Some(3262), Some(0)));
+        assert_eq!(play(&[Pattern::FullCard]), (Some(0), Some(0)));
+        let corners = Pattern::mask("#...#\n.....\n.....\n.....\n#...#").unwrap();
+        assert_eq!(play(&[corners]), play(&[Pattern::Corners]));
+        assert_eq!(play(&[]), (None, None));
+    }
+
+    #[test]
+    fn test_board_row() {
+        let lines: Vec<&str> = BOARD.lines().collect();
+        let board = Board::parse(0, &lines).unwrap();
+        let row: Vec<u32> = board.row(0).collect();
+        assert_eq!(vec![22, 13, 17, 11, 0], row);
+        let row: Vec<u32> = board.row(4).collect();
+        assert_eq!(vec![1, 12, 20, 15, 19], row);
+    }
+
+    #[test]
+    fn test_board_col() {
+        let lines: Vec<&str> = BOARD.lines().collect();
+        let board = Board::parse(0, &lines).unwrap();
+        let row: Vec<u32> = board.column(0).collect();
+        assert_eq!(vec![22, 8, 21, 6, 1], row);
+        let row: Vec<u32> = board.column(4).collect();
+        assert_eq!(vec![0, 24, 7, 5, 19], row);
+    }
+
+    #[test]
+    fn test_board_winning() {
+        let lines: Vec<&str> = BOARD.lines().collect();
+        let board = Board::parse(0, &lines).unwrap();
+        let marked: HashSet<u32> = HashSet::from([22, 13, 17, 11, 0]);
+        assert!(board.is_winning(&marked));
+        let marked: HashSet<u32> = HashSet::from([1, 12, 20, 15, 19]);
+        assert!(board.is_winning(&marked));
+        let marked: HashSet<u32> = HashSet::from([22, 8, 21, 6, 1]);
+        assert!(board.is_winning(&marked));
+        let marked: HashSet<u32> = HashSet::from([0, 24, 7, 5, 19]);
+        assert!(board.is_winning(&marked));
+    }
+
+    #[test]
+    fn test_game() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let mut game = Game::parse(&lines).unwrap();
+        println!("{:#?}", game);
+        let win = game
+            .events()
+            .find(|event| matches!(event, Event::Win { .. }));
+        let board = &game.boards[2];
+        let _color = COLOR.lock().unwrap();
+        colored::control::set_override(false);
+        let text = game.render(2);
+        colored::control::unset_override();
+        assert_eq!(
+            text,
+            "14 21 17 24  4 \n10 16 15  9 19 \n18  8 23 26 20 \n22 11 13  6  5 \n 2  0 12  3  7 \n"
+        );
+        assert_eq!(
+            HashSet::from([7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24]),
+            game.marked
+        );
+        assert_eq!(game.score(2), 4512);
+        assert_eq!(board.unmarked_sum(&game.marked) * 24, 4512);
+        assert_eq!(
+            win,
+            Some(Event::Win {
+                turn: 12,
+                board: 2,
+                rank: 1,
+                score: 4512
+            })
+        );
+    }
+
+    #[test]
+    fn test_log() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let mut game = Game::parse(&lines).unwrap();
+        let log = game.log();
+        assert_eq!(log.ranking(), [2, 0, 1]);
+        game.restart(game.numbers.clone());
+        assert_eq!(game.log(), log);
+        game.restart(vec![]);
+        assert_eq!(game.log(), Log::default());
+        let draws = log
+            .events
+            .iter()
+            .filter(|event| matches!(event, Event::Draw { .. }))
+            .count();
+        assert_eq!(draws, 27);
+        assert_eq!(
+            log.events.last(),
+            Some(&Event::Draw {
+                turn: 27,
+                number: 1
+            })
+        );
+        let scores: Vec<u64> = log
+            .events
+            .iter()
+            .filter_map(|event| match *event {
+                Event::Win { score, .. } => Some(score),
+                Event::Draw { .. } => None,
+            })
+            .collect();
+        assert_eq!(scores.first(), Some(&4512));
+        assert_eq!(scores.last(), Some(&1924));
+        // Boards winning on the same draw share it, ranked in input order.
+        let lines = ["1,2", "", "1 2", "", "2 1"];
+        let log = Game::parse(&lines).unwrap().log();
+        assert_eq!(log.ranking(), [0, 1]);
+        assert_eq!(log.events.len(), 4);
+    }
+
+    #[test]
+    fn test_render() {
+        let _color = COLOR.lock().unwrap();
+        let board = Board::parse(0, &["1 12", "3  4"]).unwrap();
+        colored::control::set_override(false);
+        assert_eq!(board.render(&HashSet::from([12])), " 1 12 \n 3  4 \n");
+        colored::control::set_override(true);
+        let text = board.render(&HashSet::from([12]));
+        colored::control::unset_override();
+        assert!(text.starts_with(" 1 \u{1b}["));
+        assert!(text.contains(&format!("{}", "12".bold().green())));
+        assert!(text.ends_with(" 3  4 \n"));
+    }
+    #[test]
+    fn test_part1() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let mut game = Game::parse(&lines).unwrap();
+        let result = game.part1();
+        assert_eq!(result, Some(4512));
+    }
+    #[test]
+    fn test_part2() {
+        let lines: Vec<&str> = DATA.lines().collect();
+        let mut game = Game::parse(&lines).unwrap();
+        let result = game.part2();
+        assert_eq!(result, Some(1924));
+    }
+    #[test]
+    fn test_no_winner() {
+        let mut lines: Vec<&str> = DATA.lines().collect();
+        lines[0] = "7,4,9";
+        let mut game = Game::parse(&lines).unwrap();
+        assert_eq!(game.clone().part1(), None);
+        assert_eq!(game.part2(), None);
+        assert!(Bingo::parse("7,4,9").unwrap().part1().is_error());
+    }
+
+    /// A game drawing every number in `0..n`, with `width` by `height` boards of distinct
+    /// numbers from that range, as `((width, height), numbers, boards)`.
+    fn game() -> impl Strategy<Value = ((usize, usize), Vec<u32>, Vec<Vec<u32>>)> {
+        (1..7usize, 1..7usize, 0..50u32).prop_flat_map(|(width, height, extra)| {
+            let numbers: Vec<u32> = (0..(width * height) as u32 + extra).collect();
+            let board = subsequence(numbers.clone(), width * height).prop_shuffle();
+            (
+                Just((width, height)),
+                Just(numbers).prop_shuffle(),
+                vec(board, 1..5),
+            )
+        })
+    }
+
+    fn format(width: usize, numbers: &[u32], boards: &[Vec<u32>]) -> String {
+        let numbers: Vec<String> = numbers.iter().map(u32::to_string).collect();
+        let mut text = numbers.join(",");
+        for board in boards {
+            text.push('\n');
+            for row in board.chunks(width) {
+                let row: Vec<String> = row.iter().map(|v| format!("{:2}", v)).collect();
+                text.push('\n');
+                text.push_str(&row.join(" "));
+            }
+        }
+        text
+    }
+
+    /// The wins of a game the slow way, as `(board, score)` in order, scanning every board on
+    /// every draw.
+    fn scanned_wins(game: &Game, patterns: &[Pattern]) -> Vec<(usize, u64)> {
+        let (width, height) = (game.boards[0].width(), game.boards[0].height());
+        let groups: Vec<Vec<Pos>> = patterns
+            .iter()
+            .flat_map(|pattern| pattern.groups(width, height))
+            .collect();
+        let mut marked = HashSet::new();
+        let mut wins: Vec<(usize, u64)> = vec![];
+        for &number in &game.numbers {
+            marked.insert(number);
+            for (index, board) in game.boards.iter().enumerate() {
+                let won = wins.iter().any(|&(winner, _)| winner == index);
+                if !won && board.wins(&groups, &marked) {
+                    wins.push((index, board.unmarked_sum(&marked) * number as u64));
+                }
+            }
+        }
+        wins
+    }
+
+    fn transposed((width, height): (usize, usize), board: &[u32]) -> Vec<u32> {
+        (0..width * height)
+            .map(|i| board[i % height * width + i / height])
+            .collect()
+    }
+
+    #[test]
+    fn test_many_boards() {
+        // A small linear congruential generator, so that the boards are the same every time.
+        let mut state = 12345u64;
+        let mut next = move |bound: u64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+        let numbers: Vec<String> = (0..100).map(|number: u32| number.to_string()).collect();
+        let mut text = numbers.join(",");
+        for _ in 0..5000 {
+            text.push('\n');
+            for _ in 0..5 {
+                let row: Vec<String> = (0..5).map(|_| next(100).to_string()).collect();
+                text.push('\n');
+                text.push_str(&row.join(" "));
+            }
+        }
+        let lines: Vec<&str> = text.lines().collect();
+        let mut game = Game::parse(&lines).unwrap();
+        let log = game.log();
+        assert_eq!(log.ranking().len(), 5000);
+        assert_eq!(log.events.len(), 5100);
+        assert!(game.won.iter().all(|&won| won));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(bingo) = Bingo::from_input(&text) {
+                bingo.part1();
+                bingo.part2();
+            }
+        }
+
+        #[test]
+        fn every_board_wins(((width, _), numbers, boards) in game()) {
+            let bingo = Bingo::parse(&format(width, &numbers, &boards)).unwrap();
+            prop_assert_eq!(bingo.game.boards.len(), boards.len());
+            prop_assert!(!bingo.part1().is_error());
+            prop_assert!(!bingo.part2().is_error());
+            let mut ranking = bingo.game.clone().log().ranking();
+            ranking.sort_unstable();
+            prop_assert_eq!(ranking, (0..boards.len()).collect::<Vec<usize>>());
+        }
+
+        #[test]
+        fn tally_matches_scan(
+            ((width, _), numbers, boards) in game(),
+            patterns in subsequence(
+                vec![
+                    Pattern::Rows,
+                    Pattern::Columns,
+                    Pattern::Diagonals,
+                    Pattern::Corners,
+                    Pattern::FullCard,
+                ],
+                0..=5,
+            ),
+            repeats in 0..10usize,
+        ) {
+            // Numbers drawn again mark nothing new.
+            let repeats = repeats.min(numbers.len());
+            let numbers = [&numbers[..repeats], &numbers[..]].concat();
+            let lines = format(width, &numbers, &boards);
+            let lines: Vec<&str> = lines.lines().collect();
+            let game = Game::parse(&lines).unwrap().with_patterns(&patterns);
+            let wins: Vec<(usize, u64)> = game
+                .clone()
+                .events()
+                .filter_map(|event| match event {
+                    Event::Win { board, score, .. } => Some((board, score)),
+                    Event::Draw { .. } => None,
+                })
+                .collect();
+            prop_assert_eq!(wins, scanned_wins(&game, &patterns));
+        }
+
+        /// Rows and columns win alike, so transposing every board changes nothing.
+        #[test]
+        fn transpose_invariant((size, numbers, boards) in game()) {
+            let bingo = Bingo::parse(&format(size.0, &numbers, &boards)).unwrap();
+            let boards: Vec<Vec<u32>> = boards.iter().map(|b| transposed(size, b)).collect();
+            let transposed = Bingo::parse(&format(size.1, &numbers, &boards)).unwrap();
+            prop_assert_eq!(transposed.part1(), bingo.part1());
+            prop_assert_eq!(transposed.part2(), bingo.part2());
+        }
+    }
+}
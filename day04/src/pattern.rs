@@ -0,0 +1,134 @@
+use aoc_grid::{Grid, Pos};
+
+/// A way of winning. Each pattern stands for groups of cells, and a board wins once every cell
+/// of one group is marked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    /// Any whole row.
+    Rows,
+    /// Any whole column.
+    Columns,
+    /// Either diagonal from a top corner. On boards that are not square, they stop at the
+    /// shorter side.
+    Diagonals,
+    /// The four corners together.
+    Corners,
+    /// Every cell of the board.
+    FullCard,
+    /// The cells set in the mask, which starts at the top left corner. A mask with cells
+    /// outside the board never wins.
+    Mask(Grid<bool>),
+}
+
+impl Pattern {
+    /// The default rules: a row or a column.
+    pub const STANDARD: [Pattern; 2] = [Pattern::Rows, Pattern::Columns];
+
+    /// A mask drawn with `#` for the cells to mark and `.` for the others.
+    pub fn mask(text: &str) -> aoc_core::Result<Pattern> {
+        let mask = Grid::parse_with(text, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("# or . expected"),
+        })?;
+        Ok(Pattern::Mask(mask))
+    }
+
+    /// The groups of cells that win on a `width` by `height` board.
+    pub fn groups(&self, width: i32, height: i32) -> Vec<Vec<Pos>> {
+        let side = width.min(height);
+        match self {
+            Pattern::Rows => (0..height)
+                .map(|y| (0..width).map(|x| Pos::new(x, y)).collect())
+                .collect(),
+            Pattern::Columns => (0..width)
+                .map(|x| (0..height).map(|y| Pos::new(x, y)).collect())
+                .collect(),
+            Pattern::Diagonals => vec![
+                (0..side).map(|i| Pos::new(i, i)).collect(),
+                (0..side).map(|i| Pos::new(width - 1 - i, i)).collect(),
+            ],
+            Pattern::Corners => {
+                let mut corners = vec![
+                    Pos::new(0, 0),
+                    Pos::new(width - 1, 0),
+                    Pos::new(0, height - 1),
+                    Pos::new(width - 1, height - 1),
+                ];
+                // Narrow boards share corners.
+                corners.sort();
+                corners.dedup();
+                vec![corners]
+            }
+            Pattern::FullCard => vec![(0..height)
+                .flat_map(|y| (0..width).map(move |x| Pos::new(x, y)))
+                .collect()],
+            Pattern::Mask(mask) => {
+                let cells: Vec<Pos> = mask.positions().filter(|&pos| mask[pos]).collect();
+                let fits = cells.iter().all(|pos| pos.x < width && pos.y < height);
+                if fits {
+                    vec![cells]
+                } else {
+                    vec![]
+                }
+            }
+        }
+        .into_iter()
+        .filter(|group: &Vec<Pos>| !group.is_empty() && width > 0 && height > 0)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups(pattern: &Pattern, width: i32, height: i32) -> Vec<Vec<(i32, i32)>> {
+        pattern
+            .groups(width, height)
+            .iter()
+            .map(|group| group.iter().map(|pos| (pos.x, pos.y)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            groups(&Pattern::Rows, 3, 2),
+            [[(0, 0), (1, 0), (2, 0)], [(0, 1), (1, 1), (2, 1)]]
+        );
+        assert_eq!(groups(&Pattern::Columns, 3, 2).len(), 3);
+        assert_eq!(
+            groups(&Pattern::Diagonals, 3, 3),
+            [[(0, 0), (1, 1), (2, 2)], [(2, 0), (1, 1), (0, 2)]]
+        );
+        assert_eq!(
+            groups(&Pattern::Diagonals, 4, 2),
+            [[(0, 0), (1, 1)], [(3, 0), (2, 1)]]
+        );
+    }
+
+    #[test]
+    fn test_shapes() {
+        assert_eq!(
+            groups(&Pattern::Corners, 5, 5),
+            [[(0, 0), (0, 4), (4, 0), (4, 4)]]
+        );
+        assert_eq!(groups(&Pattern::Corners, 1, 3), [[(0, 0), (0, 2)]]);
+        assert_eq!(groups(&Pattern::FullCard, 2, 2)[0].len(), 4);
+        assert!(Pattern::FullCard.groups(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_mask() {
+        let plus = Pattern::mask(".#.\n###\n.#.").unwrap();
+        assert_eq!(
+            groups(&plus, 5, 5),
+            [[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]]
+        );
+        assert!(plus.groups(2, 5).is_empty());
+        let error = Pattern::mask("#.\n#x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert!(Pattern::mask("..").unwrap().groups(5, 5).is_empty());
+    }
+}
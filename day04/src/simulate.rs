@@ -0,0 +1,204 @@
+use crate::{Event, Game};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// How one board did over many games with the numbers shuffled.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BoardOdds {
+    pub board: usize,
+    /// How often it was among the first boards to win.
+    pub win_probability: f64,
+    /// How often it was among the last boards to win, in games where every board won.
+    pub last_probability: f64,
+    /// The mean turn it won on, over the games where it won at all.
+    pub expected_turn: Option<f64>,
+}
+
+/// The odds of every board of a game, in input order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Odds {
+    pub trials: usize,
+    pub seed: u64,
+    pub boards: Vec<BoardOdds>,
+}
+
+impl Odds {
+    /// The board most likely to win first, and among those the one expected to win soonest.
+    pub fn best(&self) -> Option<usize> {
+        self.boards
+            .iter()
+            .max_by(|a, b| {
+                let turn = |odds: &BoardOdds| odds.expected_turn.unwrap_or(f64::INFINITY);
+                a.win_probability
+                    .total_cmp(&b.win_probability)
+                    .then(turn(b).total_cmp(&turn(a)))
+            })
+            .map(|odds| odds.board)
+    }
+
+    /// The board most likely to win last, and among those the one expected to win latest.
+    pub fn worst(&self) -> Option<usize> {
+        self.boards
+            .iter()
+            .max_by(|a, b| {
+                let turn = |odds: &BoardOdds| odds.expected_turn.unwrap_or(f64::INFINITY);
+                a.last_probability
+                    .total_cmp(&b.last_probability)
+                    .then(turn(a).total_cmp(&turn(b)))
+            })
+            .map(|odds| odds.board)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("odds only hold numbers")
+    }
+}
+
+impl Display for Odds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "trials: {}, seed: {}", self.trials, self.seed)?;
+        write!(f, "board   first    last  turn")?;
+        for odds in &self.boards {
+            write!(
+                f,
+                "\n{:5} {:6.1}% {:6.1}%",
+                odds.board,
+                odds.win_probability * 100.0,
+                odds.last_probability * 100.0
+            )?;
+            match odds.expected_turn {
+                Some(turn) => write!(f, " {:5.1}", turn)?,
+                None => write!(f, "     -")?,
+            }
+        }
+        if let (Some(best), Some(worst)) = (self.best(), self.worst()) {
+            write!(f, "\nbest: board {}\nworst: board {}", best, worst)?;
+        }
+        Ok(())
+    }
+}
+
+impl Game {
+    /// Plays the game `trials` times from the start, drawing the same numbers in a new order
+    /// each time. The same seed always gives the same odds.
+    pub fn simulate(&self, trials: usize, seed: u64) -> Odds {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut game = self.clone();
+        let count = self.boards.len();
+        let mut firsts = vec![0; count];
+        let mut lasts = vec![0; count];
+        let mut wins = vec![0; count];
+        let mut turns = vec![0; count];
+        let mut numbers = self.numbers.clone();
+        for _ in 0..trials {
+            numbers.shuffle(&mut rng);
+            game.restart(numbers.clone());
+            // The turn each board won on, in this trial.
+            let mut won: Vec<Option<usize>> = vec![None; count];
+            for event in game.events() {
+                if let Event::Win {
+                    turn, board, rank, ..
+                } = event
+                {
+                    won[board] = Some(turn);
+                    if rank == count {
+                        break;
+                    }
+                }
+            }
+            let first = won.iter().flatten().min().copied();
+            let last = won
+                .iter()
+                .all(Option::is_some)
+                .then(|| won.iter().flatten().max());
+            for (board, &turn) in won.iter().enumerate() {
+                if let Some(turn) = turn {
+                    wins[board] += 1;
+                    turns[board] += turn;
+                    firsts[board] += (Some(turn) == first) as usize;
+                    lasts[board] += (last == Some(Some(&turn))) as usize;
+                }
+            }
+        }
+        let ratio = |count: usize| match trials {
+            0 => 0.0,
+            _ => count as f64 / trials as f64,
+        };
+        Odds {
+            trials,
+            seed,
+            boards: (0..count)
+                .map(|board| BoardOdds {
+                    board,
+                    win_probability: ratio(firsts[board]),
+                    last_probability: ratio(lasts[board]),
+                    expected_turn: (wins[board] > 0)
+                        .then(|| turns[board] as f64 / wins[board] as f64),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random_input, Pattern, Shape};
+
+    /// A game won by rows only.
+    fn game(lines: &[&str]) -> Game {
+        Game::parse(lines).unwrap().with_patterns(&[Pattern::Rows])
+    }
+
+    #[test]
+    fn test_simulate() {
+        // The first board only needs the 1, so it never wins after the second one, and they win
+        // together when the 2 comes first.
+        let game = game(&["1,2,3,4", "", "1 1", "", "1 2"]);
+        let odds = game.simulate(1000, 7);
+        assert_eq!(odds, game.simulate(1000, 7));
+        let within = |value: f64, low: f64, high: f64| (low..high).contains(&value);
+        let (first, second) = (odds.boards[0], odds.boards[1]);
+        assert_eq!((first.win_probability, second.last_probability), (1.0, 1.0));
+        assert!(within(second.win_probability, 0.45, 0.55));
+        assert!(within(first.last_probability, 0.45, 0.55));
+        assert!(within(first.expected_turn.unwrap(), 2.4, 2.6));
+        assert!(within(second.expected_turn.unwrap(), 3.2, 3.45));
+        assert_eq!((odds.best(), odds.worst()), (Some(0), Some(1)));
+    }
+
+    #[test]
+    fn test_no_wins() {
+        let odds = game(&["1,2", "", "1 2 3"]).simulate(10, 0);
+        assert_eq!(odds.boards[0].win_probability, 0.0);
+        assert_eq!(odds.boards[0].expected_turn, None);
+        let odds = game(&["1"]).simulate(10, 0);
+        assert_eq!((odds.best(), odds.worst()), (None, None));
+        let odds = game(&["1", "", "1"]).simulate(0, 0);
+        assert_eq!(odds.boards[0].win_probability, 0.0);
+    }
+
+    #[test]
+    fn test_random_games() {
+        let shape = Shape {
+            boards: 10,
+            ..Shape::default()
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let text = random_input(&shape, &mut rng);
+        let lines: Vec<&str> = text.lines().collect();
+        let odds = game(&lines).simulate(200, 3);
+        let total: f64 = odds.boards.iter().map(|odds| odds.win_probability).sum();
+        // Boards winning on the same draw all count, so the total can be over 1.
+        assert!((1.0..1.5).contains(&total), "{}", total);
+        let total: f64 = odds.boards.iter().map(|odds| odds.last_probability).sum();
+        assert!((1.0..1.5).contains(&total), "{}", total);
+        assert!(odds.boards.iter().all(|odds| odds.expected_turn.is_some()));
+        let json: serde_json::Value = serde_json::from_str(&odds.to_json()).unwrap();
+        assert_eq!(json["boards"].as_array().unwrap().len(), 10);
+        assert_eq!(odds.to_string().lines().count(), 14);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::Board;
+use aoc_grid::Pos;
+use std::collections::HashMap;
+
+/// How much of every winning group is marked on every board, kept up to date one number at a
+/// time, so that a draw only costs as much as the cells holding that number.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tally {
+    /// Where each number is, as `(board, cell)` with cells counted in reading order.
+    cells: HashMap<u32, Vec<(usize, usize)>>,
+    /// The groups each cell belongs to. Every board has the same size, so they share these.
+    groups_by_cell: Vec<Vec<usize>>,
+    group_sizes: Vec<usize>,
+    /// `hits[board * group_sizes.len() + group]` cells of that group are marked on that board.
+    hits: Vec<usize>,
+    /// The sum of the numbers not marked yet, on each board, and of all its numbers.
+    unmarked: Vec<u64>,
+    totals: Vec<u64>,
+}
+
+impl Tally {
+    /// A tally with nothing marked, for boards that all have the size of the first one.
+    pub fn new(boards: &[Board], groups: &[Vec<Pos>]) -> Self {
+        let width = boards.first().map_or(0, |board| board.width());
+        let height = boards.first().map_or(0, |board| board.height());
+        let mut groups_by_cell = vec![vec![]; (width * height) as usize];
+        for (group, cells) in groups.iter().enumerate() {
+            for pos in cells {
+                groups_by_cell[(pos.y * width + pos.x) as usize].push(group);
+            }
+        }
+        let mut cells: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        for (board, values) in boards.iter().enumerate() {
+            for (cell, &value) in values.cells.values().enumerate() {
+                cells.entry(value).or_default().push((board, cell));
+            }
+        }
+        let totals: Vec<u64> = boards
+            .iter()
+            .map(|board| board.cells.values().map(|&v| v as u64).sum())
+            .collect();
+        Tally {
+            cells,
+            groups_by_cell,
+            group_sizes: groups.iter().map(Vec::len).collect(),
+            hits: vec![0; boards.len() * groups.len()],
+            unmarked: totals.clone(),
+            totals,
+        }
+    }
+
+    /// Unmarks everything, keeping the index.
+    pub fn clear(&mut self) {
+        self.hits.fill(0);
+        self.unmarked.clone_from(&self.totals);
+    }
+
+    /// Marks a number that was not marked before, and returns the boards where it completes a
+    /// group, in input order.
+    pub fn mark(&mut self, number: u32) -> Vec<usize> {
+        let mut completed = vec![];
+        let Some(cells) = self.cells.get(&number) else {
+            return completed;
+        };
+        let stride = self.group_sizes.len();
+        for &(board, cell) in cells {
+            self.unmarked[board] -= number as u64;
+            for &group in &self.groups_by_cell[cell] {
+                let hits = &mut self.hits[board * stride + group];
+                *hits += 1;
+                if *hits == self.group_sizes[group] {
+                    completed.push(board);
+                }
+            }
+        }
+        // A number can complete several groups, or sit more than once on a board.
+        completed.dedup();
+        completed
+    }
+
+    pub fn unmarked_sum(&self, board: usize) -> u64 {
+        self.unmarked[board]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pattern;
+
+    fn boards() -> Vec<Board> {
+        vec![
+            Board::parse(0, &["1 2", "3 4"]).unwrap(),
+            Board::parse(0, &["4 3", "2 2"]).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_mark() {
+        let groups = Pattern::Rows.groups(2, 2);
+        let mut tally = Tally::new(&boards(), &groups);
+        assert!(tally.mark(1).is_empty());
+        assert_eq!(tally.mark(2), [0, 1]);
+        assert_eq!((tally.unmarked_sum(0), tally.unmarked_sum(1)), (7, 7));
+        assert!(tally.mark(9).is_empty());
+        assert!(tally.mark(4).is_empty());
+        assert_eq!(tally.mark(3), [0, 1]);
+        assert_eq!(tally.unmarked_sum(1), 0);
+        tally.clear();
+        assert_eq!((tally.unmarked_sum(0), tally.unmarked_sum(1)), (10, 11));
+        assert_eq!(tally.mark(2), [1]);
+    }
+
+    #[test]
+    fn test_overlapping_groups() {
+        let groups = Pattern::FullCard.groups(2, 2);
+        let mut groups = [groups, Pattern::Diagonals.groups(2, 2)].concat();
+        groups.push(vec![Pos::new(1, 1)]);
+        let mut tally = Tally::new(&boards(), &groups);
+        assert_eq!(tally.mark(4), [0]);
+        assert_eq!(tally.mark(1), [0]);
+        assert_eq!(tally.mark(2), [1]);
+        assert_eq!(tally.mark(3), [0, 1]);
+    }
+}
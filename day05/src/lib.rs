@@ -0,0 +1,291 @@
+use aoc_core::{expect_part, parse_at, Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Vents {
+    lines: Vec<Line>,
+}
+
+impl Solution for Vents {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Vents {
+            lines: Line::parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        Map::new().part1(&self.lines).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Map::new().part2(&self.lines).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    cells: HashMap<Point, u32>,
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn add_line(&mut self, line: &Line) {
+        let mut x = line.p1.x;
+        let mut y = line.p1.y;
+       
+        let dx = delta(line.p1.x, line.p2.x);
+        let dy = delta(line.p1.y, line.p2.y);
+        loop {
+            let point = Point::new(x, y);
+            self.cells.insert(point, self.cells.get(&point).unwrap_or(&0) + 1);
+            x = x.wrapping_add_signed(dx);
+            y = y.wrapping_add_signed(dy);
+            if point == line.p2 {
+                break;
+            }
+        }
+    }
+    
+    pub fn part1(&mut self, lines : &[Line]) -> u32 {
+        for line in lines {
+            if line.is_cartesian() {
+                self.add_line(line)
+            }
+        }
+        self.cells.values().filter(| &&v| v >= 2_u32).count() as u32
+    }
+
+    pub fn part2(&mut self, lines : &[Line]) -> u32 {
+        for line in lines {
+            self.add_line(line)
+        }
+        self.cells.values().filter(| &&v| v >= 2_u32).count() as u32
+    }
+}
+
+fn delta(v1: u32, v2: u32) -> i32 {
+    match v1.cmp(&v2) {
+        std::cmp::Ordering::Less => 1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => -1,
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl Point {
+    /// Parses `str`, a slice of line `index`, which is `line`.
+    fn parse(index: usize, line: &str, str: &str) -> aoc_core::Result<Self> {
+        let mut tokens = str.trim().split(',');
+        let x = expect_part(index, line, tokens.next(), "x")?;
+        let y = expect_part(index, line, tokens.next(), "y")?;
+        Ok(Self {
+            x: parse_at(index, line, x)?,
+            y: parse_at(index, line, y)?,
+        })
+    }
+    fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    p1: Point,
+    p2: Point,
+}
+
+impl Line {
+    fn parse(index: usize, str: &str) -> aoc_core::Result<Self> {
+        let mut tokens = str.split_whitespace();
+        let p1 = expect_part(index, str, tokens.next(), "a point")?;
+        let arrow = expect_part(index, str, tokens.next(), "->")?;
+        if arrow != "->" {
+            return Err(ParseError::at(index, str, arrow, "-> expected"));
+        }
+        let p2 = expect_part(index, str, tokens.next(), "a point")?;
+        let line = Self {
+            p1: Point::parse(index, str, p1)?,
+            p2: Point::parse(index, str, p2)?,
+        };
+        if !line.is_cartesian() && line.p1.x.abs_diff(line.p2.x) != line.p1.y.abs_diff(line.p2.y) {
+            return Err(ParseError::line(
+                index,
+                str,
+                "lines should be horizontal, vertical or at 45 degrees",
+            ));
+        }
+        Ok(line)
+    }
+
+    #[cfg(test)]
+    fn new(x1: u32, y1: u32, x2: u32, y2: u32) -> Self {
+        Self {
+            p1: Point { x: x1, y: y1 },
+            p2: Point { x: x2, y: y2 },
+        }
+    }
+
+    pub fn parse_lines(str: &str) -> aoc_core::Result<Vec<Self>> {
+        str.lines()
+            .enumerate()
+            .map(|(index, line)| Self::parse(index, line))
+            .collect()
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.p1.y == self.p2.y
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.p1.x == self.p2.x
+    }
+
+    fn is_cartesian(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    const DATA: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn parse_point() {
+        let str = "12,132";
+        let p = Point::parse(0, str, str).unwrap();
+        assert_eq!(p.x, 12);
+        assert_eq!(p.y, 132);
+    }
+    #[test]
+    fn parse_line() {
+        let str = "8,0 -> 0,8";
+        let line = Line::parse(0, str).unwrap();
+        assert_eq!(line.p1.x, 8);
+        assert_eq!(line.p1.y, 0);
+        assert_eq!(line.p2.x, 0);
+        assert_eq!(line.p2.y, 8);
+    }
+    #[test]
+    fn parse_lines() {
+        let lines = Line::parse_lines(DATA).unwrap();
+        let line = &lines[0];
+        assert_eq!(line.p1.x, 0);
+        assert_eq!(line.p1.y, 9);
+        assert_eq!(line.p2.x, 5);
+        assert_eq!(line.p2.y, 9);
+        let line = &lines[lines.len() - 1];
+        assert_eq!(line.p1.x, 5);
+        assert_eq!(line.p1.y, 5);
+        assert_eq!(line.p2.x, 8);
+        assert_eq!(line.p2.y, 2);
+    }
+    #[test]
+    fn parse_error() {
+        let error = Line::parse_lines("0,9 -> 5,9\n8,0 => 0,8").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = Line::parse(0, "8,0 -> 0,y").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (10, "y"));
+        assert!(Line::parse(0, "8,0 -> 0").is_err());
+        assert!(Line::parse(0, "8,0").is_err());
+        assert!(Line::parse(0, "0,0 -> 1,2").is_err());
+    }
+    #[test]
+    fn test_cartesian_line() {
+        let line = Line::new(1, 2, 2, 6);
+        assert!(!line.is_cartesian());
+        let line = Line::new(1, 0, 1, 6);
+        assert!(line.is_cartesian());
+        let line = Line::new(0, 2, 8, 2);
+        assert!(line.is_cartesian());
+    }
+    #[test]
+    fn test_part1() {
+        let lines = Line::parse_lines(DATA).unwrap();
+        let mut map = Map::new();
+        let result = map.part1(&lines);
+        println!("result: {}", result);
+        assert_eq!(result, 5);
+    }
+    #[test]
+    fn test_part2() {
+        let lines = Line::parse_lines(DATA).unwrap();
+        let mut map = Map::new();
+        let result = map.part2(&lines);
+        println!("result: {}", result);
+        assert_eq!(result, 12);
+    }
+    #[test]
+    fn test_large_coordinates() {
+        let mut map = Map::new();
+        map.add_line(&Line::new(2147483646, 0, 2147483648, 2));
+        assert_eq!(map.cells.len(), 3);
+    }
+
+    fn line() -> impl Strategy<Value = Line> {
+        let direction = prop_oneof![Just((1, 0)), Just((0, 1)), Just((1, 1)), Just((1, -1))];
+        (0u32..50, 50u32..100, direction, 0u32..50).prop_map(|(x, y, (dx, dy), length)| {
+            let (x2, y2) = (x + dx as u32 * length, y.wrapping_add_signed(dy * length as i32));
+            Line::new(x, y, x2, y2)
+        })
+    }
+
+    fn reversed(line: &Line) -> Line {
+        Line {
+            p1: line.p2,
+            p2: line.p1,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(vents) = Vents::from_input(&text) {
+                vents.part1();
+                vents.part2();
+            }
+        }
+
+        #[test]
+        fn reversed_line_covers_same_cells(line in line()) {
+            let mut map = Map::new();
+            map.add_line(&line);
+            let mut reversed_map = Map::new();
+            reversed_map.add_line(&reversed(&line));
+            prop_assert_eq!(&reversed_map.cells, &map.cells);
+            let length = line.p1.x.abs_diff(line.p2.x).max(line.p1.y.abs_diff(line.p2.y));
+            prop_assert_eq!(map.cells.len(), length as usize + 1);
+        }
+
+        #[test]
+        fn diagonals_only_add_overlaps(lines in vec(line(), 0..20)) {
+            let part1 = Map::new().part1(&lines);
+            prop_assert!(Map::new().part2(&lines) >= part1);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_core::{parse_at, Answer, ParseError, Solution};
+
+pub struct School {
+    first_day: FreqArray,
+}
+
+impl Solution for School {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(School {
+            first_day: parse_fish(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        sum(&n_turns(self.first_day, 80)).into()
+    }
+
+    fn part2(&self) -> Answer {
+        sum(&n_turns(self.first_day, 256)).into()
+    }
+}
+
+pub type FreqArray = [usize; 9];
+
+pub fn parse_fish(str: &str) -> aoc_core::Result<FreqArray> {
+    let mut result = [0; 9];
+    for (index, line) in str.lines().enumerate() {
+        for value in line.split(',').map(str::trim).filter(|v| !v.is_empty()) {
+            let timer: usize = parse_at(index, line, value)?;
+            if timer >= result.len() {
+                return Err(ParseError::at(index, line, value, "timers go from 0 to 8"));
+            }
+            result[timer] += 1;
+        }
+    }
+    Ok(result)
+}
+
+fn turn(data: FreqArray) -> FreqArray {
+    let mut new_data = [0; 9];
+    for (i, count) in data.iter().enumerate() {
+        if i == 0 {
+            new_data[8] = *count;
+            new_data[6] = *count;
+        } else {
+            new_data[i - 1] += count;
+        }
+    }
+    new_data
+}
+
+pub fn n_turns(data: FreqArray, n: usize) -> FreqArray {
+    let mut data = data.to_owned();
+    for _ in 0..n {
+        data = turn(data);
+    }
+    data
+}
+
+pub fn sum(data: &FreqArray) -> usize {
+    data.iter().sum()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    const DATA: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_n_turns() {
+        let first_day = parse_fish(DATA).unwrap();
+        let today = n_turns(first_day, 18);
+        assert_eq!(sum(&today), 26);
+
+        let today = n_turns(first_day, 80);
+        assert_eq!(sum(&today), 5934);
+    }
+
+    #[test]
+    fn test_parse_fish() {
+        let first_day = parse_fish(DATA).unwrap();
+        assert_eq!(first_day, [0, 1, 1, 2, 1, 0, 0, 0, 0]);
+        assert_eq!(parse_fish("3,4\r\n").unwrap(), parse_fish("3,4").unwrap());
+        let error = parse_fish("3,4,9").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (5, "9"));
+        assert!(parse_fish("3,x").is_err());
+    }
+
+    #[test]
+    fn test_part2_turns() {
+        let first_day = parse_fish(DATA).unwrap();
+        
+        let day1_counts = parse_fish("2,3,2,0,1").unwrap();
+        let day1 = turn(first_day);
+        assert_eq!(day1_counts, day1);
+
+        let day2_counts = parse_fish("1,2,1,6,0,8").unwrap();
+        let day2 = turn(day1);
+        assert_eq!(day2_counts, day2);
+
+        let day3_counts = parse_fish("0,1,0,5,6,7,8").unwrap();
+        let day3 = turn(day2);
+        assert_eq!(day3_counts, day3);
+    }
+
+    #[test]
+    fn test_part2() {
+        let first_day = parse_fish(DATA).unwrap();
+        let today = n_turns(first_day, 18);
+        assert_eq!(sum(&today), 26);
+        let today = n_turns(first_day, 80);
+        assert_eq!(sum(&today), 5934);
+        let today = n_turns(first_day, 256);
+        assert_eq!(sum(&today), 26984457539);
+    }
+
+    fn school() -> impl Strategy<Value = String> {
+        vec(0usize..9, 0..300).prop_map(|timers| {
+            let timers: Vec<String> = timers.iter().map(usize::to_string).collect();
+            timers.join(",")
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(school) = School::from_input(&text) {
+                school.part1();
+                school.part2();
+            }
+        }
+
+        #[test]
+        fn turns_compose(school in school(), n in 0usize..100, m in 0usize..100) {
+            let first_day = parse_fish(&school).unwrap();
+            prop_assert_eq!(n_turns(n_turns(first_day, n), m), n_turns(first_day, n + m));
+            prop_assert!(sum(&n_turns(first_day, n + m)) >= sum(&n_turns(first_day, n)));
+        }
+
+        /// Each fish's descendants grow independently of the others.
+        #[test]
+        fn schools_add_up(a in school(), b in school(), n in 0usize..100) {
+            let joined = parse_fish(&format!("{},{}", a, b)).unwrap();
+            let (a, b) = (parse_fish(&a).unwrap(), parse_fish(&b).unwrap());
+            prop_assert_eq!(
+                sum(&n_turns(joined, n)),
+                sum(&n_turns(a, n)) + sum(&n_turns(b, n))
+            );
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use aoc_core::{parse_at, Answer, ParseError, Solution};
+
+pub struct Crabs {
+    positions: Vec<i32>,
+}
+
+impl Solution for Crabs {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Crabs {
+            positions: parse_crab_positions(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        best_consumption(&self.positions, part1_consumption).into()
+    }
+
+    fn part2(&self) -> Answer {
+        best_consumption(&self.positions, part2_consumption).into()
+    }
+}
+
+pub fn parse_crab_positions(data: &str) -> aoc_core::Result<Vec<i32>> {
+    let positions: Vec<i32> = data
+        .lines()
+        .enumerate()
+        .flat_map(|(index, line)| {
+            line.split(',')
+                .map(str::trim)
+                .map(move |value| parse_at(index, line, value))
+        })
+        .collect::<aoc_core::Result<_>>()?;
+    if positions.is_empty() {
+        return Err(ParseError::new(1, 1, "", "crab position expected"));
+    }
+    Ok(positions)
+}
+
+// Fuel is counted in i128: a single crab crossing the whole i32 range already needs almost all
+// of an i64 in part 2, and the sum over many crabs would not fit.
+pub fn part1_consumption(pos1: i32, pos2: i32) -> i128 {
+    (pos1 as i128 - pos2 as i128).abs()
+}
+
+pub fn part2_consumption(pos1: i32, pos2: i32) -> i128 {
+    let target = part1_consumption(pos1, pos2);
+    target * (target + 1) / 2
+}
+
+fn fuel_consumption<F>(position: &[i32], pos: i32, func: F) -> i128
+where
+    F: Fn(i32, i32) -> i128,
+{
+    position.iter().map(|&curr| func(pos, curr)).sum()
+}
+
+/// The least fuel needed to line every crab up. Fuel use is convex in the position, so the
+/// best position is the first one where moving one step further stops helping, and a binary
+/// search finds it without trying every position between the crabs.
+pub fn best_consumption<F>(positions: &[i32], func: F) -> i128
+where
+    F: Fn(i32, i32) -> i128 + Copy,
+{
+    let (mut min, mut max) = positions
+        .iter()
+        .fold((i32::MAX, i32::MIN), |(min, max), &v| {
+            (v.min(min), v.max(max))
+        });
+    while min < max {
+        let pos = min + ((max as i64 - min as i64) / 2) as i32;
+        if fuel_consumption(positions, pos + 1, func) >= fuel_consumption(positions, pos, func) {
+            max = pos;
+        } else {
+            min = pos + 1;
+        }
+    }
+    fuel_consumption(positions, min, func)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+    const DATA: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_parsing() {
+        let result = parse_crab_positions(DATA).unwrap();
+        assert_eq!(result.len(), 10);
+        assert_eq!(result[0], 16);
+        assert_eq!(result[result.len() - 1], 14);
+        let error = parse_crab_positions("16,1,,0").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert!(parse_crab_positions("").is_err());
+    }
+
+    #[test]
+    fn test_fueld_consumption() {
+        let data = parse_crab_positions(DATA).unwrap();
+        assert_eq!(fuel_consumption(&data, 2, part1_consumption), 37);
+        assert_eq!(fuel_consumption(&data, 1, part1_consumption), 41);
+        assert_eq!(fuel_consumption(&data, 3, part1_consumption), 39);
+        assert_eq!(fuel_consumption(&data, 10, part1_consumption), 71);
+    }
+
+    #[test]
+    fn test_best_consumption() {
+        let data = parse_crab_positions(DATA).unwrap();
+        assert_eq!(best_consumption(&data, part1_consumption), 37);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = parse_crab_positions(DATA).unwrap();
+        assert_eq!(fuel_consumption(&data, 2, part2_consumption), 206);
+        assert_eq!(best_consumption(&data, part2_consumption), 168);
+    }
+
+    #[test]
+    fn test_part2_consumption() {
+        assert_eq!(part2_consumption(0, 0), 0);
+        assert_eq!(part2_consumption(0, 1), 1);
+        assert_eq!(part2_consumption(0, 2), 3);
+        assert_eq!(part2_consumption(0, 3), 6);
+        assert_eq!(part2_consumption(0, 4), 10);
+        assert_eq!(part2_consumption(0, 5), 15);
+    }
+    #[test]
+    fn test_distant_crabs() {
+        assert_eq!(part2_consumption(0, 100_000), 5_000_050_000);
+        assert_eq!(
+            best_consumption(&[i32::MIN, i32::MAX], part1_consumption),
+            u32::MAX as i128
+        );
+        let spread = 4_000_000_000i128;
+        assert_eq!(
+            part2_consumption(-2_000_000_000, 2_000_000_000),
+            spread * (spread + 1) / 2
+        );
+        // Both crabs meet in the middle, 2e9 steps each.
+        let crabs = Crabs::from_input("-2000000000,2000000000").unwrap();
+        let half = 2_000_000_000i128;
+        assert_eq!(crabs.part2(), Answer::Int(half * (half + 1)));
+        let crabs = Crabs::from_input("-2147483648,2147483647,-2147483648,2147483647").unwrap();
+        let (near, far) = (i32::MAX as i128, i32::MAX as i128 + 1);
+        let expected = near * (near + 1) + far * (far + 1);
+        assert_eq!(crabs.part2(), Answer::Int(expected));
+        let positions = vec![i32::MAX; 1000];
+        let single = part2_consumption(i32::MIN, i32::MAX);
+        assert!(single > i64::MAX as i128 / 2);
+        assert_eq!(
+            fuel_consumption(&positions, i32::MIN, part2_consumption),
+            single * 1000
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(crabs) = Crabs::from_input(&text) {
+                crabs.part1();
+                crabs.part2();
+            }
+        }
+
+        #[test]
+        fn parse_round_trip(positions in vec(any::<i32>(), 1..50)) {
+            let text: Vec<String> = positions.iter().map(i32::to_string).collect();
+            prop_assert_eq!(parse_crab_positions(&text.join(",")).unwrap(), positions);
+        }
+
+        /// Stopping at the first position that is no better is only right because fuel use
+        /// is convex.
+        #[test]
+        fn best_is_minimum(positions in vec(-100i32..100, 1..50)) {
+            for func in [part1_consumption, part2_consumption] {
+                let brute_force = (-100..100)
+                    .map(|pos| fuel_consumption(&positions, pos, func))
+                    .min();
+                prop_assert_eq!(Some(best_consumption(&positions, func)), brute_force);
+            }
+        }
+    }
+}
@@ -1,16 +1,27 @@
-use std::{collections::HashMap, error::Error, fs};
+use aoc_core::{expect_part, Answer, ParseError, Solution};
+use std::collections::HashMap;
 
-const FILENAME: &str = "input.txt";
+pub struct Notes {
+    samples: Vec<Sample>,
+}
+
+impl Solution for Notes {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Notes {
+            samples: parse_samples(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        part1(&self.samples).into()
+    }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = fs::read_to_string(FILENAME)?;
-    let samples = parse_samples(&input);
-    println!("Part1 : {}", part1(&samples));
-    println!("Part2 : {}", part2(&samples));
-    Ok(())
+    fn part2(&self) -> Answer {
+        part2(&self.samples).into()
+    }
 }
 
-fn part1(samples: &[Sample]) -> usize {
+pub fn part1(samples: &[Sample]) -> usize {
     samples
         .iter()
         .map(|s| {
@@ -22,7 +33,7 @@ fn part1(samples: &[Sample]) -> usize {
         .sum()
 }
 
-fn part2(samples: &[Sample]) -> u32 {
+pub fn part2(samples: &[Sample]) -> u32 {
     samples
         .iter()
         .map(|s| {
@@ -34,6 +45,43 @@ fn part2(samples: &[Sample]) -> u32 {
 
 const MASK: u8 = 0b01111111;
 
+/// The segments of each digit on a display that is wired correctly.
+const DIGIT_SEGMENTS: [u8; 10] = [
+    0b01110111, 0b00100100, 0b01011101, 0b01101101, 0b00101110, 0b01101011, 0b01111011,
+    0b00100101, 0b01111111, 0b01101111,
+];
+
+/// How many digits use `wire`, and how many segments those digits have in total. Each wire
+/// of a display has a different signature, which does not change when wires are swapped.
+fn wire_signature(digits: &[u8], wire: u8) -> (usize, u32) {
+    let digits = digits.iter().filter(|&&segments| segments & (1 << wire) != 0);
+    (digits.clone().count(), digits.map(|&d| bit_count(d) as u32).sum())
+}
+
+/// Whether `patterns` are the ten digits of a display with some of its wires swapped.
+fn is_rewired_display(patterns: &[u8]) -> bool {
+    let mut wiring = [0; 7];
+    for (wire, correct_wire) in wiring.iter_mut().enumerate() {
+        let signature = wire_signature(patterns, wire as u8);
+        match (0..7).find(|&w| wire_signature(&DIGIT_SEGMENTS, w) == signature) {
+            Some(w) => *correct_wire = w,
+            None => return false,
+        }
+    }
+    let mut rewired: Vec<u8> = patterns
+        .iter()
+        .map(|&segments| {
+            (0..7)
+                .filter(|wire| segments & (1 << wire) != 0)
+                .fold(0, |result, wire| result | 1 << wiring[wire])
+        })
+        .collect();
+    rewired.sort_unstable();
+    let mut digits = DIGIT_SEGMENTS;
+    digits.sort_unstable();
+    rewired == digits
+}
+
 fn bit_count(mut value: u8) -> u8 {
     let mut count = 0;
     for _ in 0..8 {
@@ -61,39 +109,59 @@ fn char_to_shift(char: char) -> u8 {
     ((char as u32) - ('a' as u32)) as u8
 }
 
-fn parse_segments(string: &str) -> u8 {
+/// `string` is a slice of `line`, line `index` of the input.
+fn parse_segments(index: usize, line: &str, string: &str) -> aoc_core::Result<u8> {
+    let string = string.trim();
     let mut result = 0;
-    string
-        .trim()
-        .chars()
-        .for_each(|char| result += 1 << char_to_shift(char));
-    result
+    for (offset, char) in string.char_indices() {
+        if !('a'..='g').contains(&char) {
+            let part = &string[offset..offset + char.len_utf8()];
+            return Err(ParseError::at(index, line, part, "segment a to g expected"));
+        }
+        result |= 1 << char_to_shift(char);
+    }
+    Ok(result)
 }
 
-fn parse_all_segments(string: &str) -> Vec<u8> {
-    let result: Vec<u8> = string
-        .trim()
+fn parse_digit_list(
+    index: usize,
+    line: &str,
+    string: &str,
+    count: usize,
+) -> aoc_core::Result<Vec<u8>> {
+    let result = string
         .split_whitespace()
-        .map(parse_segments)
-        .collect();
-    debug_assert!(result.len() == 10);
-    result
+        .map(|digit| parse_segments(index, line, digit))
+        .collect::<aoc_core::Result<Vec<u8>>>()?;
+    if result.len() != count {
+        return Err(ParseError::at(
+            index,
+            line,
+            string.trim(),
+            format!("{} digits expected, found {}", count, result.len()),
+        ));
+    }
+    Ok(result)
 }
 
-fn parse_display_segments(string: &str) -> Vec<u8> {
-    let result: Vec<u8> = string
-        .trim()
-        .split_whitespace()
-        .map(parse_segments)
-        .collect();
-    debug_assert!(result.len() == 4);
-    result
+fn parse_all_segments(index: usize, line: &str, string: &str) -> aoc_core::Result<Vec<u8>> {
+    parse_digit_list(index, line, string, 10)
+}
+
+fn parse_display_segments(index: usize, line: &str, string: &str) -> aoc_core::Result<Vec<u8>> {
+    parse_digit_list(index, line, string, 4)
 }
 
-fn parse_samples(string: &str) -> Vec<Sample> {
-    string.trim().lines().map(Sample::parse).collect()
+pub fn parse_samples(string: &str) -> aoc_core::Result<Vec<Sample>> {
+    string
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| Sample::parse(index, line))
+        .collect()
 }
 
+#[derive(Debug)]
 pub struct Sample {
     //    segments: Vec<u8>,
     display: Vec<u8>,
@@ -101,21 +169,36 @@ pub struct Sample {
 }
 
 impl Sample {
-    fn parse(string: &str) -> Self {
-        let mut itr = string.trim().split('|');
-        let segments = parse_all_segments(itr.next().unwrap());
-        let display = parse_display_segments(itr.next().unwrap());
+    fn parse(index: usize, string: &str) -> aoc_core::Result<Self> {
+        let mut itr = string.split('|');
+        let patterns = itr.next().unwrap_or_default();
+        let segments = parse_all_segments(index, string, patterns)?;
+        if !is_rewired_display(&segments) {
+            return Err(ParseError::at(
+                index,
+                string,
+                patterns.trim(),
+                "the ten digits of a display expected",
+            ));
+        }
+        let display_part = expect_part(index, string, itr.next(), "|")?;
+        let display = parse_display_segments(index, string, display_part)?;
+        for (part, digit) in display_part.split_whitespace().zip(&display) {
+            if !segments.contains(digit) {
+                return Err(ParseError::at(index, string, part, "one of the ten digits expected"));
+            }
+        }
         let digits = segments
             .iter()
             .copied()
             .enumerate()
             .map(|(index, segments)| Digit::new(index, segments))
             .collect();
-        Self {
+        Ok(Self {
             //            segments,
             display,
             digits,
-        }
+        })
     }
 
     fn digit_indexes_for_length(&self, length: u8) -> Vec<usize> {
@@ -124,7 +207,7 @@ impl Sample {
             .enumerate()
             .filter_map(|(index, digit)| {
                 if bit_count(digit.segments) == length {
-                    Some(index as usize)
+                    Some(index)
                 } else {
                     None
                 }
@@ -219,7 +302,7 @@ impl Sample {
         digit0.digit = Some(0);
         debug_assert!(digit0.segment_count == 6);
 
-        found_index = (found_index as i32 - 1).abs() as usize;
+        found_index = (found_index as i32 - 1).unsigned_abs() as usize;
         debug_assert!(found_index == 0 || found_index == 1);
 
         let mut digit6 = self.digits[indexes[found_index]];
@@ -282,7 +365,7 @@ impl Sample {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct Digit {
     index: usize,
     digit: Option<u8>,
@@ -304,6 +387,7 @@ impl Digit {
 #[cfg(test)]
 mod testing {
     use super::*;
+    use proptest::{collection::vec, prelude::*};
     const DATA: &str =
         "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
@@ -323,19 +407,19 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
     fn test_part2() {
         let input = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |
 cdfeb fcadb cdfeb cdbaf";
-        let sample = Sample::parse(input);
+        let sample = Sample::parse(0, input).unwrap();
         let segments_to_digit = sample.infer_segments_to_digit();
         let result = sample.decode_display(segments_to_digit);
         assert_eq!(result, 5353);
 
-        let samples = parse_samples(DATA);
+        let samples = parse_samples(DATA).unwrap();
         let result = part2(&samples);
         assert_eq!(result, 61229);
     }
 
     #[test]
     fn test_part1() {
-        let samples = parse_samples(DATA);
+        let samples = parse_samples(DATA).unwrap();
         let count: usize = samples
             .iter()
             .map(|s| {
@@ -350,27 +434,35 @@ cdfeb fcadb cdfeb cdbaf";
 
     #[test]
     fn test_parse_samples() {
-        let samples = parse_samples(DATA);
+        let samples = parse_samples(DATA).unwrap();
         assert_eq!(samples.len(), 10);
     }
 
     #[test]
     fn test_parse_sample() {
-        let _sample = Sample::parse(LINE);
+        let _sample = Sample::parse(0, LINE).unwrap();
+        let error = Sample::parse(2, "gcafb gcf dcaebfg | fgae cfgab fg bagce").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = Sample::parse(0, &LINE.replace("gaef", "gaex")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (38, "x"));
+        assert!(Sample::parse(0, &LINE.replace('|', " ")).is_err());
+        let error = Sample::parse(0, &LINE.replace("gaef ", "gaed ")).unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.message, "the ten digits of a display expected");
+        let error = Sample::parse(0, &LINE.replace("| fgae", "| fga")).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (62, "fga"));
     }
 
-    const DIGIT_SEGMENTS: [u8; 10] = [
-        0b01110111, 0b00100100, 0b01011101, 0b01101101, 0b00101110, 0b01101011, 0b01111011,
-        0b00100101, 0b01111111, 0b01101111,
-    ];
-
     #[test]
     fn test_parse_segments() {
         let segments = [
             "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
         ];
         for i in 0..10 {
-            assert_eq!(parse_segments(segments[i]), DIGIT_SEGMENTS[i]);
+            assert_eq!(
+                parse_segments(0, segments[i], segments[i]),
+                Ok(DIGIT_SEGMENTS[i])
+            );
         }
     }
 
@@ -381,4 +473,47 @@ cdfeb fcadb cdfeb cdbaf";
             assert_eq!(bit_count(DIGIT_SEGMENTS[i]), counts[i]);
         }
     }
+
+    /// A display line for a random wiring, showing `number`.
+    fn sample() -> impl Strategy<Value = (String, u32)> {
+        let wiring = Just(vec!['a', 'b', 'c', 'd', 'e', 'f', 'g']).prop_shuffle();
+        let order = Just((0..10).collect::<Vec<usize>>()).prop_shuffle();
+        (wiring, order, vec(0..10usize, 4)).prop_map(|(wiring, order, shown)| {
+            let pattern = |digit: usize| -> String {
+                (0..7)
+                    .filter(|wire| DIGIT_SEGMENTS[digit] & (1 << wire) != 0)
+                    .map(|wire| wiring[wire])
+                    .collect()
+            };
+            let patterns: Vec<String> = order.into_iter().map(pattern).collect();
+            let display: Vec<String> = shown.iter().map(|&digit| pattern(digit)).collect();
+            let number = shown.iter().fold(0, |number, &digit| number * 10 + digit as u32);
+            (format!("{} | {}", patterns.join(" "), display.join(" ")), number)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(notes) = Notes::from_input(&text) {
+                notes.part1();
+                notes.part2();
+            }
+        }
+
+        #[test]
+        fn decodes_any_wiring((line, number) in sample()) {
+            let sample = Sample::parse(0, &line).unwrap();
+            prop_assert_eq!(part2(&[sample]), number);
+        }
+
+        /// Swapping wires never changes how many segments a digit has.
+        #[test]
+        fn unique_counts((line, number) in sample()) {
+            let sample = Sample::parse(0, &line).unwrap();
+            let shown = format!("{:04}", number);
+            let expected = shown.chars().filter(|&c| "1478".contains(c)).count();
+            prop_assert_eq!(part1(&[sample]), expected);
+        }
+    }
 }
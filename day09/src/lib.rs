@@ -0,0 +1,328 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Grid, Neighborhood, Pos};
+use std::collections::{HashSet, VecDeque};
+
+impl Solution for HeightMap {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        HeightMap::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.risk_level_sum().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.top_basins_product().into()
+    }
+}
+
+#[derive(Debug)]
+pub struct HeightMap {
+    heights: Grid<u8>,
+}
+
+impl HeightMap {
+    pub fn from_string(string: &str) -> aoc_core::Result<HeightMap> {
+        Ok(HeightMap {
+            heights: Grid::parse_digits(string)?,
+        })
+    }
+
+    pub fn at(&self, x: i32, y: i32) -> Option<Pos> {
+        self.heights.pos(x, y)
+    }
+
+    pub fn at_index(&self, i: usize) -> Option<Pos> {
+        self.heights.pos_at_index(i)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.heights.positions()
+    }
+
+    pub fn value(&self, pos: Pos) -> u8 {
+        self.heights[pos]
+    }
+
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.heights.neighbors(pos, Neighborhood::Orthogonal)
+    }
+
+    pub fn low_points(&self) -> impl Iterator<Item = LowPoint> + '_ {
+        self.positions()
+            .filter_map(move |p| LowPoint::try_new(p, self))
+    }
+
+    pub fn risk_level_sum(&self) -> i32 {
+        self.low_points().map(|lp| lp.risk_level(self)).sum()
+    }
+
+    pub fn top_basins_product(&self) -> usize {
+        let mut result = self
+            .low_points()
+            .map(|p| p.get_basin(self))
+            .collect::<Vec<usize>>();
+        result.sort();
+        result.into_iter().rev().take(3).product()
+    }
+}
+
+#[derive(Debug)]
+pub struct LowPoint(Pos);
+
+impl LowPoint {
+    fn try_new(pos: Pos, hm: &HeightMap) -> Option<LowPoint> {
+        let value = hm.value(pos);
+        if hm.neighbors(pos).all(|neighbor| hm.value(neighbor) > value) {
+            Some(LowPoint(pos))
+        } else {
+            None
+        }
+    }
+
+    fn risk_level(self, hm: &HeightMap) -> i32 {
+        hm.value(self.0) as i32 + 1
+    }
+
+    fn get_basin(&self, hm: &HeightMap) -> usize {
+        // A lone 9 is a low point with no neighbors, but not part of any basin.
+        let Some(start) = BasinPoint::try_new(self.0, hm) else {
+            return 0;
+        };
+        let mut found = HashSet::from([self.0]);
+        let mut queue = VecDeque::from([start]);
+        let mut result = 1;
+        while let Some(current) = queue.pop_front() {
+            for neighbor in hm.neighbors(current.0) {
+                if let Some(bp) = BasinPoint::try_new(neighbor, hm) {
+                    if !found.contains(&bp.0) {
+                        result += 1;
+                        found.insert(bp.0);
+                        queue.push_back(bp);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BasinPoint(Pos);
+
+impl BasinPoint {
+    fn try_new(pos: Pos, hm: &HeightMap) -> Option<BasinPoint> {
+        if hm.value(pos) < 9 {
+            Some(BasinPoint(pos))
+        } else {
+            None
+        }
+    }
+}
+
+// #[derive(Debug)]
+// struct Basin<'a> {
+//     points: Vec<BasinPoint<'a>>,
+// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_matches;
+    use proptest::{collection::vec, prelude::*};
+
+    const TEST_DATA: &str = "2199943210\r\n\
+         3987894921\r\n\
+         9856789892\r\n\
+         8767896789\r\n\
+         9899965678";
+
+    #[test]
+    fn test_parsing() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.heights.width(), 10);
+        assert_eq!(hm.heights.height(), 5);
+        let error = HeightMap::from_string("219\r\n39a").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_at() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_matches!(hm.at(0, 0), Some(_));
+        assert_matches!(hm.at(9, 4), Some(_));
+        assert_matches!(hm.at(10, 4), None);
+        assert_matches!(hm.at(9, 5), None);
+    }
+
+    #[test]
+    fn test_pos_value() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_matches!(hm.at(0, 0).map(|p| hm.value(p)), Some(2));
+        assert_matches!(hm.at(9, 4).map(|p| hm.value(p)), Some(8));
+        assert_matches!(hm.at(10, 4), None);
+        assert_matches!(hm.at(9, 5), None);
+    }
+
+    #[test]
+    fn test_at_index() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_matches!(hm.at_index(0).map(|p| hm.value(p)), Some(2));
+        assert_matches!(hm.at_index(49).map(|p| hm.value(p)), Some(8));
+        assert_matches!(hm.at_index(50), None);
+        assert_matches!(hm.at_index(59), None);
+    }
+
+    #[test]
+    fn test_height_map_iterator() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.positions().count(), 50);
+    }
+
+    #[test]
+    fn test_neighbors_iterator() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.neighbors(hm.at(0, 0).unwrap()).count(), 2);
+        assert_eq!(hm.neighbors(hm.at(1, 0).unwrap()).count(), 3);
+        assert_eq!(hm.neighbors(hm.at(0, 3).unwrap()).count(), 3);
+        assert_eq!(hm.neighbors(hm.at(3, 3).unwrap()).count(), 4);
+        assert_eq!(hm.neighbors(hm.at(9, 0).unwrap()).count(), 2);
+        assert_eq!(hm.neighbors(hm.at(9, 1).unwrap()).count(), 3);
+        assert_eq!(hm.neighbors(hm.at(0, 4).unwrap()).count(), 2);
+        assert_eq!(hm.neighbors(hm.at(9, 4).unwrap()).count(), 2);
+        assert_eq!(hm.neighbors(hm.at(5, 4).unwrap()).count(), 3);
+    }
+
+    #[test]
+    fn test_low_points() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.low_points().count(), 4);
+    }
+
+    #[test]
+    fn test_risk_level() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_matches!(
+            LowPoint::try_new(hm.at(1, 0).unwrap(), &hm).map(|lp| lp.risk_level(&hm)),
+            Some(2)
+        );
+        assert_matches!(
+            LowPoint::try_new(hm.at(9, 0).unwrap(), &hm).map(|lp| lp.risk_level(&hm)),
+            Some(1)
+        );
+        assert_matches!(
+            LowPoint::try_new(hm.at(2, 2).unwrap(), &hm).map(|lp| lp.risk_level(&hm)),
+            Some(6)
+        );
+        assert_matches!(
+            LowPoint::try_new(hm.at(6, 4).unwrap(), &hm).map(|lp| lp.risk_level(&hm)),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_risk_level_sum() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.risk_level_sum(), 15);
+    }
+
+    #[test]
+    fn test_basin_point_new() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_matches!(
+            hm.at(0, 0).and_then(|p| BasinPoint::try_new(p, &hm)),
+            Some(_)
+        );
+        assert_matches!(hm.at(2, 0).and_then(|p| BasinPoint::try_new(p, &hm)), None);
+    }
+
+    #[test]
+    fn test_get_basin() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(
+            hm.at(1, 0)
+                .and_then(|p| LowPoint::try_new(p, &hm))
+                .map(|lp| lp.get_basin(&hm)),
+            Some(3)
+        );
+        assert_eq!(
+            hm.at(9, 0)
+                .and_then(|p| LowPoint::try_new(p, &hm))
+                .map(|lp| lp.get_basin(&hm)),
+            Some(9)
+        );
+        assert_eq!(
+            hm.at(2, 2)
+                .and_then(|p| LowPoint::try_new(p, &hm))
+                .map(|lp| lp.get_basin(&hm)),
+            Some(14)
+        );
+        assert_eq!(
+            hm.at(6, 4)
+                .and_then(|p| LowPoint::try_new(p, &hm))
+                .map(|lp| lp.get_basin(&hm)),
+            Some(9)
+        );
+    }
+
+    #[test]
+    fn test_top_basins_product() {
+        let hm = HeightMap::from_string(TEST_DATA).unwrap();
+        assert_eq!(hm.top_basins_product(), 1134);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let lf = HeightMap::from_string(&TEST_DATA.replace("\r\n", "\n")).unwrap();
+        assert_eq!(lf.heights.height(), 5);
+        assert_eq!(lf.risk_level_sum(), 15);
+        assert_eq!(lf.top_basins_product(), 1134);
+        let with_bom = format!("\u{feff}{}\r\n", TEST_DATA);
+        assert_eq!(
+            HeightMap::from_input(&with_bom).unwrap().risk_level_sum(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_lone_nine() {
+        let hm = HeightMap::from_string("9").unwrap();
+        assert_eq!(hm.risk_level_sum(), 10);
+        assert_eq!(hm.top_basins_product(), 0);
+    }
+
+    fn height_map() -> impl Strategy<Value = Vec<String>> {
+        let row = |width| vec(prop::char::range('0', '9'), width).prop_map(String::from_iter);
+        (1..12usize).prop_flat_map(move |width| vec(row(width), 1..12))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(hm) = HeightMap::from_input(&text) {
+                hm.part1();
+                hm.part2();
+            }
+        }
+
+        /// Low points and basins do not depend on which way the map is read.
+        #[test]
+        fn mirror_invariant(rows in height_map()) {
+            let hm = HeightMap::from_string(&rows.join("\n")).unwrap();
+            let mirrored: Vec<String> = rows.iter().map(|r| r.chars().rev().collect()).collect();
+            let mirrored = HeightMap::from_string(&mirrored.join("\n")).unwrap();
+            prop_assert_eq!(mirrored.risk_level_sum(), hm.risk_level_sum());
+            prop_assert_eq!(mirrored.top_basins_product(), hm.top_basins_product());
+        }
+
+        #[test]
+        fn basins_are_bounded(rows in height_map()) {
+            let hm = HeightMap::from_string(&rows.join("\n")).unwrap();
+            let below_nine = hm.positions().filter(|&p| hm.value(p) < 9).count();
+            for low_point in hm.low_points() {
+                prop_assert!(hm.value(low_point.0) < 9 || hm.positions().count() == 1);
+                prop_assert!(low_point.get_basin(&hm) <= below_nine);
+            }
+        }
+    }
+}
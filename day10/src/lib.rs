@@ -0,0 +1,393 @@
+#[cfg(test)]
+use aoc_core::assert_matches;
+use aoc_core::{Answer, ParseError, Solution};
+use std::{collections::HashMap, iter::zip};
+
+pub struct Subsystem {
+    results: Vec<LineResult>,
+}
+
+impl Solution for Subsystem {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Ok(Subsystem {
+            results: parse_text(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        corruption_points(&mut self.results.iter().copied()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let Some(mut completion_scores) = completion_scores(&mut self.results.iter().copied())
+        else {
+            return Answer::Error("completion score overflow".to_owned());
+        };
+        completion_scores.sort();
+        completion_scores
+            .get(completion_scores.len() / 2)
+            .copied()
+            .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LineResult {
+    Valid,
+    Corrupted(char),
+    /// The completion score, or `None` if it does not fit in a `usize`.
+    Completed(Option<usize>),
+}
+
+const OPENERS: [char; 4] = ['(', '[', '{', '<'];
+const CLOSERS: [char; 4] = [')', ']', '}', '>'];
+
+fn is_opener(c: &char) -> bool {
+    OPENERS.contains(c)
+}
+
+fn is_closer(c: &char) -> bool {
+    CLOSERS.contains(c)
+}
+
+fn char_to_corruption_score(char: char) -> i32 {
+    match char {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("Cannot score this character {}", char),
+    }
+}
+
+fn char_to_completion_score(char: char) -> usize {
+    match char {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => panic!("Cannot score this character {}", char),
+    }
+}
+
+struct State {
+    stack: Vec<char>,
+    brackets: HashMap<char, char>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self {
+            brackets: zip(OPENERS, CLOSERS).collect(),
+            stack: vec![],
+        }
+    }
+}
+
+fn parse_chunk(chars: &mut impl Iterator<Item = char>, state: &mut State) -> LineResult {
+    state.stack.clear();
+    for char in chars {
+        if is_closer(&char) {
+            if let Some(expected) = state.stack.pop() {
+                if expected == char {
+                    if state.stack.is_empty() {
+                        return LineResult::Valid;
+                    } else {
+                        continue;
+                    }
+                } else {
+                    return LineResult::Corrupted(char);
+                }
+            } else {
+                return LineResult::Corrupted(char);
+            }
+        } else if is_opener(&char) {
+            state.stack.push(*state.brackets.get(&char).unwrap());
+        } else {
+            unreachable!("parse_line only passes brackets, got {}", char);
+        }
+    }
+    // Scores grow five-fold per bracket, so very long completions overflow.
+    let mut score = Some(0usize);
+    while let Some(char) = state.stack.pop() {
+        score = score
+            .and_then(|score| score.checked_mul(5))
+            .and_then(|score| score.checked_add(char_to_completion_score(char)));
+    }
+    LineResult::Completed(score)
+}
+
+fn parse_line(index: usize, line: &str, state: &mut State) -> aoc_core::Result<LineResult> {
+    if let Some((offset, char)) = line
+        .char_indices()
+        .find(|(_, c)| !is_opener(c) && !is_closer(c))
+    {
+        let part = &line[offset..offset + char.len_utf8()];
+        return Err(ParseError::at(index, line, part, "bracket expected"));
+    }
+    let mut chars = line.chars().peekable();
+    while chars.peek().is_some() {
+        let result = parse_chunk(&mut chars, state);
+        match result {
+            LineResult::Valid => continue,
+            _ => return Ok(result),
+        }
+    }
+    Ok(LineResult::Valid)
+}
+
+pub fn parse_text(text: &str) -> aoc_core::Result<Vec<LineResult>> {
+    let mut state = State::new();
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line, &mut state))
+        .collect()
+}
+
+pub fn corruption_points(results: &mut impl Iterator<Item = LineResult>) -> i32 {
+    results.fold(0, |total, result| {
+        if let LineResult::Corrupted(char) = result {
+            total + char_to_corruption_score(char)
+        } else {
+            total
+        }
+    })
+}
+
+/// The scores of the incomplete lines, or `None` if any of them overflows.
+pub fn completion_scores(results: &mut impl Iterator<Item = LineResult>) -> Option<Vec<usize>> {
+    results
+        .filter_map(|result| {
+            if let LineResult::Completed(n) = result {
+                Some(n)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    const TEXT: &str = "[({(<(())[]>[[{[]{<()<>>\r\n\
+[(()[<>])]({[<{<<[]>>(\r\n\
+{([(<{}[<>[]}>{[]{[(<()>\r\n\
+(((({<>}<{<{<>}{[]{[]{}\r\n\
+[[<[([]))<([[{}[[()]]]\r\n\
+[{[{({}]{}}([{[{{{}}([]\r\n\
+{<[[]]>}<{[{[{[]{()[[[]\r\n\
+[<(<(<(<{}))><([]([]()\r\n\
+<{([([[(<>()){}]>(<<{{\r\n\
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn test_parse_chunk() {
+        let mut state = State::new();
+        assert_matches!(
+            parse_chunk(&mut "()".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "[]".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "([])".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "{()()()}".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "<([{}])>".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "[<>({}){}[([])<>]]".chars(), &mut state),
+            LineResult::Valid
+        );
+        assert_matches!(
+            parse_chunk(&mut "(((((((((())))))))))".chars(), &mut state),
+            LineResult::Valid
+        );
+
+        assert_matches!(
+            parse_chunk(&mut "(]".chars(), &mut state),
+            LineResult::Corrupted(_)
+        );
+        assert_matches!(
+            parse_chunk(&mut "{()()()>".chars(), &mut state),
+            LineResult::Corrupted(_)
+        );
+        assert_matches!(
+            parse_chunk(&mut "(((()))}".chars(), &mut state),
+            LineResult::Corrupted(_)
+        );
+        assert_matches!(
+            parse_chunk(&mut "<([]){()}[{}])".chars(), &mut state),
+            LineResult::Corrupted(_)
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let mut state = State::new();
+        const ERRORS: [(&str, char); 5] = [
+            ("{([(<{}[<>[]}>{[]{[(<()>", '}'),
+            ("[[<[([]))<([[{}[[()]]]", ')'),
+            ("[{[{({}]{}}([{[{{{}}([]", ']'),
+            ("[<(<(<(<{}))><([]([]()", ')'),
+            ("<{([([[(<>()){}]>(<<{{", '>'),
+        ];
+        let corrupted = TEXT.split("\r\n").filter_map(|str| {
+            if let Ok(LineResult::Corrupted(c)) = parse_line(0, str, &mut state) {
+                Some((str, c))
+            } else {
+                None
+            }
+        });
+        zip(corrupted, ERRORS).for_each(|((str1, c1), (str2, c2))| {
+            assert_eq!(str1, str2);
+            assert_eq!(c1, c2);
+        });
+        let error = parse_text("[]\r\n[(x)]").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_corruption_points() {
+        let result = corruption_points(&mut parse_text(TEXT).unwrap().into_iter());
+        assert_eq!(result, 26397);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let lf = parse_text(&TEXT.replace("\r\n", "\n")).unwrap();
+        assert_eq!(lf.len(), 10);
+        assert_eq!(corruption_points(&mut lf.into_iter()), 26397);
+        let with_bom = format!("\u{feff}{}\n", TEXT);
+        let subsystem = Subsystem::from_input(&with_bom).unwrap();
+        assert_eq!(subsystem.part1(), Answer::Int(26397));
+    }
+
+    #[test]
+    fn test_completion_score() {
+        let mut state = State::new();
+        const COMPLETED: [(&str, usize); 5] = [
+            ("[({(<(())[]>[[{[]{<()<>>", 288957),
+            ("[(()[<>])]({[<{<<[]>>(", 5566),
+            ("(((({<>}<{<{<>}{[]{[]{}", 1480781),
+            ("{<[[]]>}<{[{[{[]{()[[[]", 995444),
+            ("<{([{{}}[<[[[<>{}]]]>[]]", 294),
+        ];
+        let completed = TEXT.split("\r\n").filter_map(|str| {
+            if let Ok(LineResult::Completed(Some(n))) = parse_line(0, str, &mut state) {
+                Some((str, n))
+            } else {
+                None
+            }
+        });
+        zip(completed, COMPLETED).for_each(|((str1, n1), (str2, n2))| {
+            assert_eq!(str1, str2);
+            assert_eq!(n1, n2);
+        })
+    }
+
+    #[test]
+    fn test_middle_completion_score() {
+        let mut completion_scores =
+            completion_scores(&mut parse_text(TEXT).unwrap().into_iter()).unwrap();
+        completion_scores.sort();
+        assert_eq!(completion_scores[completion_scores.len() / 2], 288957);
+    }
+
+    #[test]
+    fn test_long_completion() {
+        let mut state = State::new();
+        let result = parse_line(0, &"(".repeat(100), &mut state).unwrap();
+        assert_matches!(result, LineResult::Completed(None));
+        // 28 brackets still fit in 64 bits, 29 do not.
+        let result = parse_line(0, &"(".repeat(28), &mut state).unwrap();
+        assert_matches!(result, LineResult::Completed(Some(_)));
+        let result = parse_line(0, &"(".repeat(29), &mut state).unwrap();
+        assert_matches!(result, LineResult::Completed(None));
+        let result = parse_line(0, "(", &mut state).unwrap();
+        assert_matches!(result, LineResult::Completed(Some(_)));
+        let subsystem = Subsystem::from_input(&format!("((\n{}", "(".repeat(100))).unwrap();
+        assert_eq!(
+            subsystem.part2(),
+            Answer::Error("completion score overflow".to_owned())
+        );
+    }
+
+    /// A balanced line, built from `steps`: 0 to 3 open a bracket, 4 to 7 close one.
+    fn balanced(steps: &[usize]) -> String {
+        let mut line = String::new();
+        let mut stack = vec![];
+        for &step in steps {
+            match stack.pop() {
+                Some(closer) if step >= 4 => line.push(closer),
+                popped => {
+                    stack.extend(popped);
+                    line.push(OPENERS[step % 4]);
+                    stack.push(CLOSERS[step % 4]);
+                }
+            }
+        }
+        line.extend(stack.into_iter().rev());
+        line
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(subsystem) = Subsystem::from_input(&text) {
+                subsystem.part1();
+                subsystem.part2();
+            }
+        }
+
+        #[test]
+        fn balanced_is_valid(steps in vec(0..8usize, 0..100)) {
+            let mut state = State::new();
+            let line = balanced(&steps);
+            prop_assert!(matches!(parse_line(0, &line, &mut state), Ok(LineResult::Valid)));
+        }
+
+        /// Appending the closers that a completion score stands for makes the line valid.
+        #[test]
+        fn prefix_completes(steps in vec(0..8usize, 1..25), cut in any::<prop::sample::Index>()) {
+            let mut state = State::new();
+            let line = balanced(&steps);
+            let mut prefix = line[..cut.index(line.len())].to_owned();
+            let mut score = match parse_line(0, &prefix, &mut state).unwrap() {
+                LineResult::Completed(score) => score.unwrap(),
+                LineResult::Valid => 0,
+                LineResult::Corrupted(c) => return Err(TestCaseError::fail(format!("{}", c))),
+            };
+            let mut completion = vec![];
+            while score > 0 {
+                completion.push(CLOSERS[score % 5 - 1]);
+                score /= 5;
+            }
+            prefix.extend(completion.into_iter().rev());
+            prop_assert!(matches!(parse_line(0, &prefix, &mut state), Ok(LineResult::Valid)));
+        }
+
+        #[test]
+        fn wrong_closer_corrupts(steps in vec(0..8usize, 1..100), shift in 1..4usize) {
+            let mut state = State::new();
+            let line = balanced(&steps);
+            let (index, closer) = line.char_indices().find(|(_, c)| is_closer(c)).unwrap();
+            let position = CLOSERS.iter().position(|&c| c == closer).unwrap();
+            let wrong = CLOSERS[(position + shift) % 4];
+            let line = format!("{}{}{}", &line[..index], wrong, &line[index + 1..]);
+            let result = parse_line(0, &line, &mut state).unwrap();
+            prop_assert!(matches!(result, LineResult::Corrupted(c) if c == wrong));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Neighborhood, Pos};
+use std::collections::HashSet;
+
+impl Solution for Grid {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Grid::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let steps = self.step_iter();
+        steps
+            .skip(1)
+            .take(100)
+            .map(|g| g.flash_count())
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.first_synchronized_step().into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid {
+    levels: aoc_grid::Grid<i32>,
+}
+
+impl Grid {
+    pub fn from_string(string: &str) -> aoc_core::Result<Grid> {
+        Ok(Grid {
+            levels: aoc_grid::Grid::parse_digits(string)?,
+        })
+    }
+
+    pub fn sub_step1(&mut self) {
+        self.levels.values_mut().for_each(|level| *level += 1);
+    }
+
+    pub fn sub_step2(&mut self) {
+        // we did dijkstra yesterday, let do it quick and dirty this time
+        loop {
+            let flashables = self.flashables();
+            if flashables.is_empty() {
+                break;
+            } else {
+                for pos in flashables {
+                    self.levels[pos] = 0;
+                    let neighbors = self
+                        .levels
+                        .neighbors(pos, Neighborhood::All)
+                        .filter(|&n| self.levels[n] != 0)
+                        .collect::<Vec<Pos>>();
+                    for &pos in neighbors.iter() {
+                        self.levels[pos] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn flash_count(&self) -> i32 {
+        self.levels.values().filter(|&&level| level == 0).count() as i32
+    }
+
+    pub fn is_flashing_synchronized(&self) -> bool {
+        self.levels.values().all(|&level| level == 0)
+    }
+
+    pub fn flashables(&self) -> Vec<Pos> {
+        self.iter().filter(|&pos| self.levels[pos] > 9).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.levels.positions()
+    }
+
+    /// The first step where all octopuses flash, unless the grid repeats itself before that.
+    pub fn first_synchronized_step(&self) -> Option<usize> {
+        let mut seen = HashSet::new();
+        self.step_iter()
+            .take_while(|grid| seen.insert(grid.clone()))
+            .position(|grid| grid.is_flashing_synchronized())
+    }
+
+    pub fn step_iter(&self) -> impl Iterator<Item = Grid> + '_ {
+        GridStepIterator {
+            current_grid: self.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct GridStepIterator {
+    current_grid: Grid,
+}
+
+impl Iterator for GridStepIterator {
+    type Item = Grid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut new_grid = self.current_grid.clone();
+        new_grid.sub_step1();
+        new_grid.sub_step2();
+        Some(std::mem::replace(&mut self.current_grid, new_grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    #[test]
+    fn test_grid_from_string() {
+        let grid = Grid::from_string(TEXT).unwrap();
+        assert_eq!(grid.levels.width(), 10);
+        assert_eq!(grid.levels.height(), 10);
+        assert_eq!(grid.levels.len(), 100);
+        assert!(Grid::from_string("123\n4-6").is_err());
+    }
+
+    #[test]
+    fn test_grid_eq() {
+        let grid1 = Grid::from_string(TEXT).unwrap();
+        let grid2 = Grid::from_string(TEXT).unwrap();
+        let mut grid3 = Grid::from_string(TEXT).unwrap();
+        grid3.levels[Pos::new(0, 0)] = 0;
+        assert_eq!(grid1, grid2);
+        assert_ne!(grid1, grid3);
+    }
+
+    #[test]
+    fn test_small_steps() {
+        const SMALL_GRID_TEXTS: [&str; 3] = [
+            "11111
+19991
+19191
+19991
+11111",
+            "34543
+40004
+50005
+40004
+34543",
+            "45654
+51115
+61116
+51115
+45654",
+        ];
+        let grids = SMALL_GRID_TEXTS
+            .into_iter()
+            .map(Grid::from_string)
+            .collect::<aoc_core::Result<Vec<Grid>>>()
+            .unwrap();
+        assert_eq!(grids.len(), 3);
+        grids.iter().for_each(|g| {
+            assert_eq!(g.levels.width(), 5);
+            assert_eq!(g.levels.height(), 5);
+        });
+        let iterated = grids[0].step_iter().take(3).collect::<Vec<Grid>>();
+        assert_eq!(grids, iterated);
+    }
+
+    #[test]
+    fn test_big_steps() {
+        let grid = Grid::from_string(TEXT).unwrap();
+        let steps = grid.step_iter();
+        let flashes: i32 = steps.skip(1).take(100).map(|g| g.flash_count()).sum();
+        assert_eq!(flashes, 1656);
+    }
+
+    #[test]
+    fn test_all_synchronized() {
+        let grid = Grid::from_string(TEXT).unwrap();
+        let steps = grid.step_iter();
+        let (index, _) = steps
+            .enumerate()
+            .find(|(_, grid)| grid.is_flashing_synchronized())
+            .unwrap();
+        assert_eq!(index, 195);
+    }
+
+    #[test]
+    fn test_never_synchronized() {
+        let grid = Grid::from_string("02").unwrap();
+        assert_eq!(grid.first_synchronized_step(), None);
+        assert!(grid.part2().is_error());
+    }
+
+    fn grid() -> impl Strategy<Value = String> {
+        let row = |width| vec(prop::char::range('0', '9'), width).prop_map(String::from_iter);
+        (1..8usize).prop_flat_map(move |width| vec(row(width), 1..8).prop_map(|r| r.join("\n")))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(grid) = Grid::from_input(&text) {
+                grid.part1();
+                grid.part2();
+            }
+        }
+
+        /// Every step leaves energy levels between 0 and 9, and the ones that flashed at 0.
+        #[test]
+        fn steps_reset_flashes(text in grid()) {
+            let grid = Grid::from_string(&text).unwrap();
+            for grid in grid.step_iter().take(30) {
+                prop_assert!(grid.levels.values().all(|level| (0..=9).contains(level)));
+                prop_assert!(grid.flash_count() as usize <= grid.levels.len());
+            }
+        }
+
+        /// Once every octopus flashes together, they keep doing so every ten steps.
+        #[test]
+        fn synchronized_stays_synchronized(text in grid()) {
+            let grid = Grid::from_string(&text).unwrap();
+            if let Some(step) = grid.first_synchronized_step() {
+                let later = grid.step_iter().nth(step + 10).unwrap();
+                prop_assert!(later.is_flashing_synchronized());
+            }
+        }
+    }
+
+    const TEXT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+}
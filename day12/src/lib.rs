@@ -1,22 +1,26 @@
 //#![allow(dead_code)]
 
+use aoc_core::{expect_part, Answer, ParseError, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 use std::hash::Hash;
 
-const FILENAME: &str = "input.txt";
+impl Solution for Graph {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Graph::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.path_iter::<FastRoute>().count().into()
+    }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let file_data = read_to_string(FILENAME)?;
-    let graph = Graph::from_string(&file_data);
-    println!("Part 1 : {}", graph.path_iter::<FastRoute>().count());
-    println!("Part 2 : {}", graph.path_iter::<ScenicRoute>().count());
-    Ok(())
+    fn part2(&self) -> Answer {
+        self.path_iter::<ScenicRoute>().count().into()
+    }
 }
 
 #[derive(Debug)]
-struct Node {
+pub struct Node {
     name: String,
     is_big: bool,
     is_end: bool,
@@ -37,7 +41,7 @@ impl Node {
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct NodeIndex(usize);
+pub struct NodeIndex(usize);
 
 impl NodeIndex {
     fn new(index: usize) -> Self {
@@ -49,19 +53,45 @@ impl NodeIndex {
 }
 
 #[derive(Default, Debug)]
-struct Graph {
+pub struct Graph {
     name_to_node_index: HashMap<String, NodeIndex>,
     nodes: Vec<Node>,
 }
 
 impl Graph {
-    pub fn from_string(text: &str) -> Graph {
+    pub fn from_string(text: &str) -> aoc_core::Result<Graph> {
         let mut result = Graph::default();
-        text.trim().lines().for_each(|line| {
+        let mut line_count = 0;
+        for (index, line) in text.lines().enumerate() {
+            line_count = index + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
             let mut parts = line.split('-');
-            result.new_edge(parts.next().unwrap(), parts.next().unwrap());
-        });
-        result
+            let from = parts.next().unwrap_or_default();
+            let to = expect_part(index, line, parts.next(), "-")?;
+            for name in [from, to] {
+                if name.is_empty() || !name.chars().all(char::is_alphabetic) {
+                    return Err(ParseError::at(index, line, name, "cave name expected"));
+                }
+            }
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::at(index, line, extra, "a single - expected"));
+            }
+            if Node::new(from).is_big && Node::new(to).is_big {
+                // Paths could go back and forth between them forever.
+                return Err(ParseError::line(index, line, "big caves cannot be connected"));
+            }
+            result.new_edge(from, to);
+        }
+        for name in ["start", "end"] {
+            if result.node_index_by_name(name).is_none() {
+                let message = format!("{} cave expected", name);
+                return Err(ParseError::new(line_count + 1, 1, "", message));
+            }
+        }
+        Ok(result)
     }
 
     pub fn ensure_node_name(&mut self, name: &str) -> NodeIndex {
@@ -99,8 +129,14 @@ impl Graph {
             self.ensure_node_name(from_name),
             self.ensure_node_name(to_name),
         );
+        if self.node(from_idx).edges.contains(&to_idx) {
+            // A repeated edge is the same passage, not another way through.
+            return;
+        }
         self.node_mut(from_idx).edges.push(to_idx);
-        self.node_mut(to_idx).edges.push(from_idx);
+        if from_idx != to_idx {
+            self.node_mut(to_idx).edges.push(from_idx);
+        }
     }
 
     pub fn path_iter<Sr: Route + Default + 'static>(
@@ -125,7 +161,7 @@ impl Graph {
     }
 }
 
-trait Route {
+pub trait Route {
     fn insert(&mut self, index: NodeIndex);
     fn contains(&self, index: &NodeIndex) -> bool;
     fn remove(&mut self, index: &NodeIndex);
@@ -145,10 +181,10 @@ impl Route for FastRoute {
     }
 }
 
-type FastRoute = HashSet<NodeIndex>;
+pub type FastRoute = HashSet<NodeIndex>;
 
 #[derive(Debug, Default)]
-struct ScenicRoute {
+pub struct ScenicRoute {
     twice_visited: Option<NodeIndex>,
     fast_route: FastRoute,
 }
@@ -249,6 +285,7 @@ impl<'a, Sr: Route> PathIterator<'a, Sr> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::{collection::vec, prelude::*, sample::select};
 
     #[test]
     fn test_is_big() {
@@ -258,16 +295,24 @@ mod tests {
 
     #[test]
     fn test_graph_from_string() {
-        let graph = Graph::from_string(EXAMPLE1);
+        let graph = Graph::from_string(EXAMPLE1).unwrap();
         assert_eq!(graph.nodes.len(), 6);
         assert_eq!(graph.name_to_node_index.len(), 6);
         assert_eq!(graph.node_by_name("start").unwrap().edges.len(), 2);
         assert_eq!(graph.node_by_name("A").unwrap().edges.len(), 4);
+        let error = Graph::from_string("start-A\nA-b-end").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = Graph::from_string("start-A\nA end").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "A end"));
+        let error = Graph::from_string("start-A\nA-b").unwrap_err();
+        assert_eq!(error.message, "end cave expected");
+        let error = Graph::from_string("start-A\nA-B\nB-end").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
     }
 
     #[test]
     fn test_path_iterator() {
-        let graph = Graph::from_string(EXAMPLE1);
+        let graph = Graph::from_string(EXAMPLE1).unwrap();
 
         // for path in graph.path_iter::<ScenicRoute>() {
         //     println!("{:?}", graph.path_to_string(&path));
@@ -275,22 +320,70 @@ mod tests {
         // assert!(false);
 
         assert_eq!(graph.path_iter::<FastRoute>().count(), 10);
-        let graph = Graph::from_string(EXAMPLE2);
+        let graph = Graph::from_string(EXAMPLE2).unwrap();
         assert_eq!(graph.path_iter::<FastRoute>().count(), 19);
-        let graph = Graph::from_string(EXAMPLE3);
+        let graph = Graph::from_string(EXAMPLE3).unwrap();
         assert_eq!(graph.path_iter::<FastRoute>().count(), 226);
     }
 
     #[test]
     fn test_scenice_route_path_iterator() {
-        let graph = Graph::from_string(EXAMPLE1);
+        let graph = Graph::from_string(EXAMPLE1).unwrap();
         assert_eq!(graph.path_iter::<ScenicRoute>().count(), 36);
-        let graph = Graph::from_string(EXAMPLE2);
+        let graph = Graph::from_string(EXAMPLE2).unwrap();
         assert_eq!(graph.path_iter::<ScenicRoute>().count(), 103);
-        let graph = Graph::from_string(EXAMPLE3);
+        let graph = Graph::from_string(EXAMPLE3).unwrap();
         assert_eq!(graph.path_iter::<ScenicRoute>().count(), 3509);
     }
 
+    /// Small graphs, as they have few enough paths to list them all.
+    fn graph() -> impl Strategy<Value = String> {
+        let cave = || select(vec!["start", "end", "a", "b", "c", "d", "A", "B"]);
+        let edge = (cave(), cave()).prop_filter("big caves", |(from, to)| {
+            !(Node::new(from).is_big && Node::new(to).is_big)
+        });
+        let inner = || select(vec!["a", "b", "A"]);
+        (inner(), inner(), vec(edge, 0..10)).prop_map(|(first, last, edges)| {
+            let mut lines = vec![format!("start-{}", first), format!("{}-end", last)];
+            lines.extend(edges.iter().map(|(from, to)| format!("{}-{}", from, to)));
+            lines.join("\n")
+        })
+    }
+
+    fn check_paths<R: Route + Default + 'static>(graph: &Graph, twice: usize) -> usize {
+        let paths: HashSet<Vec<NodeIndex>> = graph.path_iter::<R>().collect();
+        for path in &paths {
+            assert!(graph.node(path[0]).is_start);
+            assert!(graph.node(*path.last().unwrap()).is_end);
+            let mut small: Vec<NodeIndex> = path
+                .iter()
+                .copied()
+                .filter(|&index| !graph.node(index).is_big)
+                .collect();
+            let count = small.len();
+            small.sort_by_key(NodeIndex::index);
+            small.dedup();
+            assert!(count - small.len() <= twice, "{}", graph.path_to_string(path));
+        }
+        assert_eq!(paths.len(), graph.path_iter::<R>().count(), "paths are distinct");
+        paths.len()
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            let _ = Graph::from_input(&text);
+        }
+
+        #[test]
+        fn paths_are_valid(text in graph()) {
+            let graph = Graph::from_string(&text).unwrap();
+            let part1 = check_paths::<FastRoute>(&graph, 0);
+            let part2 = check_paths::<ScenicRoute>(&graph, 1);
+            prop_assert!(part2 >= part1);
+        }
+    }
+
     const EXAMPLE1: &str = "start-A
 start-b
 A-c
@@ -0,0 +1,297 @@
+//#![allow(dead_code)]
+
+use aoc_core::{expect_part, parse_at, Answer, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::rc::Rc;
+
+impl Solution for Problem {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Problem::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.apply_instruction()
+            .map(|problem| problem.points.len())
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.iter().last().map(|problem| problem.to_string()).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    points: HashSet<(i32, i32)>,
+    instructions: Vec<Instruction>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Vertical(i32),
+    Horizontal(i32),
+}
+
+impl Problem {
+    /// Points, a blank line, then fold instructions.
+    pub fn from_string(text: &str) -> aoc_core::Result<Self> {
+        let mut points = HashSet::new();
+        let mut instructions = vec![];
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if instructions.is_empty() && line.contains(',') {
+                let mut parts = line.split(',');
+                let x = parts.next().unwrap_or_default();
+                let y = expect_part(index, line, parts.next(), "y")?;
+                points.insert((parse_point(index, line, x)?, parse_point(index, line, y)?));
+            } else {
+                instructions.push(Instruction::from_string(index, line)?);
+            }
+        }
+        Ok(Problem {
+            points,
+            instructions,
+        })
+    }
+
+    pub fn apply_instruction(&self) -> Option<Self> {
+        if let Some(&instruction) = self.instructions.first() {
+            let points = match instruction {
+                Instruction::Horizontal(y) => fold(&self.points, y, |pair| &mut pair.1),
+                Instruction::Vertical(x) => fold(&self.points, x, |pair| &mut pair.0),
+            };
+            Some(Problem {
+                points,
+                instructions: self.instructions[1..].to_vec(),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Rc<Self>> + '_ {
+        ProblemIterator {
+            prob: Some(Rc::new(self.clone())),
+        }
+    }
+}
+
+struct ProblemIterator {
+    prob: Option<Rc<Problem>>,
+}
+
+impl Iterator for ProblemIterator {
+    type Item = Rc<Problem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.prob = self
+            .prob
+            .take()
+            .and_then(|prob| prob.apply_instruction())
+            .map(Rc::new);
+        self.prob.clone()
+    }
+}
+
+fn parse_point(index: usize, line: &str, part: &str) -> aoc_core::Result<i32> {
+    let value = parse_at(index, line, part)?;
+    if value < 0 {
+        return Err(ParseError::at(index, line, part, "coordinates start at 0"));
+    }
+    Ok(value)
+}
+
+fn fold_around(fold_pos: i32, pos: i32) -> i32 {
+    if pos > fold_pos {
+        fold_pos - (pos - fold_pos)
+    } else {
+        pos
+    }
+}
+
+fn fold(
+    points: &HashSet<(i32, i32)>,
+    fold_pos: i32,
+    val: impl Fn(&mut (i32, i32)) -> &mut i32,
+) -> HashSet<(i32, i32)> {
+    let mut result = HashSet::default();
+    for point in points {
+        let mut new_point = *point;
+        let pos = val(&mut new_point);
+        *pos = fold_around(fold_pos, *pos);
+        result.insert(new_point);
+    }
+    result
+}
+
+impl Instruction {
+    /// Parses `text`, line `index` of the input.
+    pub fn from_string(index: usize, text: &str) -> aoc_core::Result<Self> {
+        let last = text.split(' ').next_back().unwrap_or_default();
+        let mut relevant = last.split('=');
+        let direction = relevant.next().unwrap_or_default();
+        let value = parse_point(index, text, expect_part(index, text, relevant.next(), "=")?)?;
+        match direction {
+            "x" => Ok(Instruction::Vertical(value)),
+            "y" => Ok(Instruction::Horizontal(value)),
+            _ => Err(ParseError::at(index, text, direction, "x or y expected")),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Points beyond twice the fold line end up at negative coordinates.
+        let (min_x, min_y) = self
+            .points
+            .iter()
+            .fold((0, 0), |(mx, my), (x, y)| (mx.min(*x), my.min(*y)));
+        let (max_x, max_y) = self
+            .points
+            .iter()
+            .fold((0, 0), |(mx, my), (x, y)| (mx.max(*x), my.max(*y)));
+        let width = (max_x - min_x) as usize + 1;
+        let mut lines = vec![vec!['.'; width]; (max_y - min_y) as usize + 1];
+        for (x, y) in self.points.iter() {
+            lines[(y - min_y) as usize][(x - min_x) as usize] = '#';
+        }
+        f.write_str(
+            &lines
+                .into_iter()
+                .map(|v| v.into_iter().collect())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::hash_set, prelude::*};
+
+    #[test]
+    fn test_problem_from_string() {
+        let prob = Problem::from_string(SAMPLE).unwrap();
+        assert_eq!(prob.points.len(), 18);
+        assert_eq!(prob.instructions.len(), 2);
+        let error = Problem::from_string("6,10\n0,-1").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Problem::from_string("6,10\n\nfold along z=3").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (3, 12, "z")
+        );
+        assert!(Problem::from_string("6,10\n\nfold along y").is_err());
+        assert!(Problem::from_string("6,10\n\nfold along y=a").is_err());
+        assert!(Problem::from_string("6,10\n\nfold along y=-2").is_err());
+    }
+
+    #[test]
+    fn test_fold_pos() {
+        assert_eq!(fold_around(7, 10), 4);
+    }
+
+    #[test]
+    fn test_fold_once() {
+        let prob = Problem::from_string(SAMPLE).unwrap();
+        let new_prob = prob.apply_instruction();
+        assert!(new_prob.is_some());
+        assert_eq!(new_prob.as_ref().unwrap().points.len(), 17);
+        let new_prob = new_prob.unwrap().apply_instruction();
+        assert!(new_prob.is_some());
+        assert_eq!(new_prob.unwrap().points.len(), 16);
+    }
+
+    #[test]
+    fn test_iter_and_display() {
+        let result = Problem::from_string(SAMPLE).unwrap().iter().last().unwrap();
+        assert_eq!(
+            format!("{}", result),
+            "#####
+#...#
+#...#
+#...#
+#####"
+        );
+    }
+
+    #[test]
+    fn test_fold_past_zero() {
+        let prob = Problem::from_string("0,0\n9,0\n\nfold along x=2").unwrap();
+        assert_eq!(prob.part2(), Answer::from("#....#"));
+    }
+
+    fn problem(points: HashSet<(i32, i32)>, instruction: Instruction) -> Problem {
+        Problem {
+            points,
+            instructions: vec![instruction; 2],
+        }
+    }
+
+    fn points() -> impl Strategy<Value = HashSet<(i32, i32)>> {
+        hash_set((0..80, 0..80), 0..50)
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            (0..40).prop_map(Instruction::Vertical),
+            (0..40).prop_map(Instruction::Horizontal),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(prob) = Problem::from_input(&text) {
+                prob.part1();
+                prob.part2();
+            }
+        }
+
+        #[test]
+        fn fold_is_idempotent(points in points(), instruction in instruction()) {
+            let once = problem(points, instruction).apply_instruction().unwrap();
+            let twice = once.apply_instruction().unwrap();
+            prop_assert_eq!(&twice.points, &once.points);
+        }
+
+        #[test]
+        fn fold_merges_points(points in points(), instruction in instruction()) {
+            let folded = problem(points.clone(), instruction).apply_instruction().unwrap();
+            prop_assert!(folded.points.len() <= points.len());
+            let shown = folded.to_string();
+            prop_assert_eq!(shown.matches('#').count(), folded.points.len());
+            match instruction {
+                Instruction::Vertical(x) => prop_assert!(folded.points.iter().all(|p| p.0 <= x)),
+                Instruction::Horizontal(y) => prop_assert!(folded.points.iter().all(|p| p.1 <= y)),
+            }
+        }
+    }
+
+    const SAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+}
@@ -0,0 +1,238 @@
+//#![allow(dead_code)]
+
+use aoc_core::{expect_part, Answer, ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+impl Solution for Prob {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Prob::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.recursive_score(10).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.recursive_score(40).into()
+    }
+}
+
+type ScoreMap = HashMap<char, usize>;
+type Rules = HashMap<(char, char), char>;
+
+#[derive(Debug)]
+pub struct Prob {
+    template: Vec<char>,
+    rules: Rules,
+}
+
+impl Prob {
+    pub fn from_string(text: &str) -> aoc_core::Result<Self> {
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty());
+        let (_, template) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "polymer template expected"))?;
+        let template = template.chars().collect::<Vec<char>>();
+        let mut rules = HashMap::default();
+        for (index, line) in lines {
+            let mut parts = line.split(" -> ");
+            let pair = parts.next().unwrap_or_default();
+            let insertion = expect_part(index, line, parts.next(), "->")?;
+            let pair_chars = pair.chars().collect::<Vec<char>>();
+            let insertion_chars = insertion.chars().collect::<Vec<char>>();
+            match (pair_chars.as_slice(), insertion_chars.as_slice()) {
+                (&[a, b], &[c]) => {
+                    rules.insert((a, b), c);
+                }
+                (&[_, _], _) => {
+                    let message = "one element expected";
+                    return Err(ParseError::at(index, line, insertion, message));
+                }
+                _ => return Err(ParseError::at(index, line, pair, "two elements expected")),
+            }
+        }
+        Ok(Prob { template, rules })
+    }
+
+    pub fn recursive_score(&self, recursion_count: usize) -> usize {
+        fn merge_scores(into: &mut ScoreMap, other: &ScoreMap) {
+            for (char, score) in other.iter() {
+                into.insert(*char, into.get(char).unwrap_or(&0) + score);
+            }
+        }
+        fn inc_score(into: &mut ScoreMap, char: char) {
+            into.insert(char, into.get(&char).unwrap_or(&0) + 1);
+        }
+
+        type Cache = HashMap<(usize, (char, char)), Rc<ScoreMap>>;
+
+        fn recurse(
+            cache: &mut Cache,
+            rules: &Rules,
+            depth_left: usize,
+            pair: (char, char),
+        ) -> Rc<ScoreMap> {
+            let mut scores = ScoreMap::default();
+            if depth_left > 0 {
+                if let Some(insert_char) = rules.get(&pair) {
+                    inc_score(&mut scores, *insert_char);
+                    merge_scores(
+                        &mut scores,
+                        &call_cached(cache, rules, depth_left - 1, (pair.0, *insert_char)),
+                    );
+                    merge_scores(
+                        &mut scores,
+                        &call_cached(cache, rules, depth_left - 1, (*insert_char, pair.1)),
+                    );
+                }
+                // Pairs without a rule stay as they are.
+            }
+            Rc::new(scores)
+        }
+
+        fn call_cached(
+            cache: &mut Cache,
+            rules: &Rules,
+            depth_left: usize,
+            pair: (char, char),
+        ) -> Rc<ScoreMap> {
+            let cache_key = (depth_left, pair);
+            if let Some(scores) = cache.get(&cache_key) {
+                scores.clone()
+            } else {
+                let score = recurse(cache, rules, depth_left, pair);
+                cache.insert(cache_key, score);
+                cache.get(&cache_key).unwrap().clone()
+            }
+        }
+
+        
+        let mut vd = VecDeque::with_capacity(3);
+        let mut scores = ScoreMap::default();
+        let mut cache = Cache::default();
+        for char in &self.template {
+            inc_score(&mut scores, *char);
+            vd.push_back(*char);
+            if vd.len() > 2 {
+                vd.pop_front();
+            }
+            if vd.len() == 2 {
+                merge_scores(
+                    &mut scores,
+                    &call_cached(&mut cache, &self.rules, recursion_count, (vd[0], vd[1])),
+                );
+            }
+        }
+        let (min, max) = scores
+            .values()
+            .fold((usize::MAX, usize::MIN), |(min, max), &score| {
+                (min.min(score), max.max(score))
+            });
+        max - min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    #[test]
+    fn test_prob_from_string() {
+        let prob = Prob::from_string(SAMPLE).unwrap();
+        assert_eq!(prob.template, ['N', 'N', 'C', 'B']);
+        assert_eq!(prob.rules.len(), 16);
+        assert_eq!(*prob.rules.get(&('N', 'C')).unwrap(), 'B');
+        let error = Prob::from_string("NNCB\n\nCH -> B\nHHH -> N").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (4, "HHH"));
+        let error = Prob::from_string("NNCB\n\nCH -> BC").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert!(Prob::from_string("NNCB\n\nCH B").is_err());
+        assert!(Prob::from_string("").is_err());
+    }
+
+    #[test]
+    fn test_recursive() {
+        let prob = Prob::from_string(SAMPLE).unwrap();
+        assert_eq!(prob.recursive_score(10), 1588);
+        assert_eq!(prob.recursive_score(40), 2188189693529);
+    }
+
+    #[test]
+    fn test_missing_rule() {
+        let prob = Prob::from_string("NNC\n\nNN -> C").unwrap();
+        // NNC, NCNC, NCNC...
+        assert_eq!(prob.recursive_score(3), 0);
+        let prob = Prob::from_string("NNC\n\nNN -> N").unwrap();
+        assert_eq!(prob.recursive_score(2), 5 - 1);
+    }
+
+    /// Steps the polymer one insertion at a time.
+    fn expand(prob: &Prob, steps: usize) -> usize {
+        let mut polymer = prob.template.clone();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                next.extend(prob.rules.get(&(pair[0], pair[1])));
+                next.push(pair[1]);
+            }
+            polymer = next;
+        }
+        let mut counts = ScoreMap::default();
+        for c in polymer {
+            *counts.entry(c).or_default() += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    fn prob() -> impl Strategy<Value = String> {
+        let insertion = prop::option::of(prop::char::range('A', 'C'));
+        ("[ABC]{1,8}", vec(insertion, 9)).prop_map(|(template, insertions)| {
+            let pairs = "ABC".chars().flat_map(|a| "ABC".chars().map(move |b| (a, b)));
+            let rules = pairs.zip(insertions).filter_map(|((a, b), insertion)| {
+                insertion.map(|c| format!("{}{} -> {}", a, b, c))
+            });
+            format!("{}\n\n{}", template, rules.collect::<Vec<_>>().join("\n"))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(prob) = Prob::from_input(&text) {
+                prob.part1();
+                prob.part2();
+            }
+        }
+
+        #[test]
+        fn counts_match_expansion(text in prob(), steps in 0..8usize) {
+            let prob = Prob::from_string(&text).unwrap();
+            prop_assert_eq!(prob.recursive_score(steps), expand(&prob, steps));
+        }
+    }
+
+    const SAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+}
@@ -0,0 +1,306 @@
+//#![allow(dead_code)]
+
+use aoc_core::{Answer, Solution};
+use aoc_grid::{Grid, Neighborhood, Pos};
+use std::collections::{BinaryHeap, HashMap};
+
+impl Solution for Board {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Board::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.a_star().into()
+    }
+
+    fn part2(&self) -> Answer {
+        BigBoard::from_board(self.clone()).a_star().into()
+    }
+}
+
+pub type Level = i32;
+pub type Coord = (i32, i32);
+
+pub trait TBoard {
+    fn width(&self) -> i32;
+    fn height(&self) -> i32;
+    fn level(&self, coord: Coord) -> i32;
+    fn top_left_coord(&self) -> Coord {
+        (0, 0)
+    }
+    fn bottom_right_coord(&self) -> Coord;
+
+    /// The lowest total risk from the top left to the bottom right, unless the board is empty.
+    fn a_star(&self) -> Option<i32> {
+        let dest = self.bottom_right_coord();
+        let start = self.top_left_coord();
+
+        let mut gscore: HashMap<Coord, i32> = HashMap::default();
+        gscore.insert(start, 0);
+
+        let mut open_list = BinaryHeap::new();
+        open_list.push(CoordCost::new(start, 0));
+
+        while let Some(CoordCost {
+            data: current,
+            cost: curr_cost,
+        }) = open_list.pop()
+        {
+            let score = gscore[&current];
+            if current == dest {
+                return Some(score);
+            }
+            if curr_cost > score + manathan_distance(current, dest) {
+                // a cheaper path to current was queued after this one
+                continue;
+            }
+            Neighborhood::Orthogonal
+                .offsets()
+                .iter()
+                .filter_map(|(dx, dy)| {
+                    let x = current.0 + dx;
+                    let y = current.1 + dy;
+                    if x >= 0 && x < self.width() && y >= 0 && y < self.height() {
+                        Some((x, y))
+                    } else {
+                        None
+                    }
+                })
+                .for_each(|neighbour| {
+                    let new_score = score + self.level(neighbour);
+                    if new_score < *gscore.get(&neighbour).unwrap_or(&i32::MAX) {
+                        gscore.insert(neighbour, new_score);
+                        open_list.push(CoordCost::new(
+                            neighbour,
+                            new_score + manathan_distance(neighbour, dest),
+                        ));
+                    }
+                })
+        }
+        None
+    }
+}
+
+#[inline]
+fn manathan_distance(from: Coord, to: Coord) -> i32 {
+    (from.0 - to.0).abs() + (from.1 - to.1).abs()
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Board {
+    levels: Grid<Level>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct CoordCost {
+    data: Coord,
+    cost: i32,
+}
+
+impl PartialOrd for CoordCost {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CoordCost {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl CoordCost {
+    pub fn new(data: Coord, cost: i32) -> Self {
+        Self { data, cost }
+    }
+}
+
+impl TBoard for Board {
+    #[inline]
+    fn width(&self) -> i32 {
+        self.levels.width()
+    }
+
+    #[inline]
+    fn height(&self) -> i32 {
+        self.levels.height()
+    }
+
+    #[inline]
+    fn level(&self, coord: Coord) -> i32 {
+        self.levels[Pos::new(coord.0, coord.1)]
+    }
+
+    #[inline]
+    fn bottom_right_coord(&self) -> Coord {
+        (self.width() - 1, self.height() - 1)
+    }
+}
+
+impl Board {
+    pub fn from_string(text: &str) -> aoc_core::Result<Board> {
+        // The distance heuristic assumes every step costs at least 1.
+        let levels = Grid::parse_with(text, |c| match c {
+            '1'..='9' => Ok(c as Level - '0' as Level),
+            _ => Err("risk level 1 to 9 expected"),
+        })?;
+        Ok(Board { levels })
+    }
+}
+
+pub struct BigBoard {
+    board: Board,
+}
+
+impl BigBoard {
+    pub fn from_board(board: Board) -> Self {
+        BigBoard { board }
+    }
+    #[cfg(test)]
+    fn from_string(text: &str) -> Self {
+        Self::from_board(Board::from_string(text).unwrap())
+    }
+}
+
+impl TBoard for BigBoard {
+    #[inline]
+    fn width(&self) -> i32 {
+        self.board.width() * 5
+    }
+
+    #[inline]
+    fn height(&self) -> i32 {
+        self.board.height() * 5
+    }
+
+    #[inline]
+    fn level(&self, coord: Coord) -> i32 {
+        let x_chunk = coord.0 / self.board.width();
+        let y_chunk = coord.1 / self.board.height();
+        let x = coord.0 % self.board.width();
+        let y = coord.1 % self.board.height();
+        (self.board.level((x, y)) - 1 + x_chunk + y_chunk) % 9 + 1
+    }
+
+    #[inline]
+    fn bottom_right_coord(&self) -> Coord {
+        (self.width() - 1, self.height() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    #[test]
+    fn test_from_string() {
+        let board = Board::from_string(SAMPLE).unwrap();
+        assert_eq!(board.width(), 10);
+        assert_eq!(board.height(), 10);
+        assert_eq!(board.levels.len(), 100);
+        let error = Board::from_string("12\n3").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "3"));
+        let error = Board::from_string("12\n30").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_manathan_cmp() {
+        assert!(CoordCost::new((5, 6), 1) < CoordCost::new((0, 0), 0));
+        assert!(CoordCost::new((5, 6), 0) > CoordCost::new((1, 1), 1));
+    }
+
+    #[test]
+    fn test_astar() {
+        let board = Board::from_string(SAMPLE).unwrap();
+        assert_eq!(board.a_star(), Some(40));
+        assert_eq!(Board::from_string("").unwrap().a_star(), None);
+    }
+
+    #[test]
+    fn test_cheaper_path_found_later() {
+        let board = BigBoard::from_string("4\n4\n5\n5");
+        assert_eq!(board.a_star(), Some(81));
+    }
+
+    #[test]
+    fn test_big_board_from_string() {
+        let board = BigBoard::from_string(SAMPLE);
+        assert_eq!(board.width(), 50);
+        assert_eq!(board.height(), 50);
+        assert_eq!(board.bottom_right_coord(), (49, 49));
+        assert_eq!(board.level((0, 0)), 1);
+        assert_eq!(board.level((10, 0)), 2);
+        assert_eq!(board.level((10, 10)), 3);
+        assert_eq!(board.level((20, 10)), 4);
+        assert_eq!(board.level((20, 20)), 5);
+        assert_eq!(board.level((1, 1)), 3);
+        assert_eq!(board.level((11, 1)), 4);
+        assert_eq!(board.level((11, 11)), 5);
+        assert_eq!(board.level((21, 11)), 6);
+        assert_eq!(board.level((21, 21)), 7);
+        assert_eq!(board.level((49, 49)), 9);
+    }
+
+    #[test]
+    fn test_big_board_astar() {
+        let board = BigBoard::from_string(SAMPLE);
+        assert_eq!(board.a_star(), Some(315));
+    }
+
+    /// Relaxes every edge until nothing changes, which is slow but obviously right.
+    fn lowest_risk(board: &impl TBoard) -> i32 {
+        let (width, height) = (board.width(), board.height());
+        let mut risk = vec![vec![i32::MAX; width as usize]; height as usize];
+        risk[0][0] = 0;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for y in 0..height {
+                for x in 0..width {
+                    for (dx, dy) in Neighborhood::Orthogonal.offsets() {
+                        let (nx, ny) = (x + dx, y + dy);
+                        if nx < 0 || nx >= width || ny < 0 || ny >= height {
+                            continue;
+                        }
+                        let from = risk[ny as usize][nx as usize];
+                        let to = from.saturating_add(board.level((x, y)));
+                        if to < risk[y as usize][x as usize] {
+                            risk[y as usize][x as usize] = to;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        risk[height as usize - 1][width as usize - 1]
+    }
+
+    fn board() -> impl Strategy<Value = String> {
+        let row = |width| vec(prop::char::range('1', '9'), width).prop_map(String::from_iter);
+        (1..8usize).prop_flat_map(move |width| vec(row(width), 1..8).prop_map(|r| r.join("\n")))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(board) = Board::from_input(&text) {
+                board.part1();
+                board.part2();
+            }
+        }
+
+        #[test]
+        fn a_star_is_optimal(text in board()) {
+            let board = Board::from_string(&text).unwrap();
+            prop_assert_eq!(board.a_star(), Some(lowest_risk(&board)));
+            let big_board = BigBoard::from_board(board);
+            prop_assert_eq!(big_board.a_star(), Some(lowest_risk(&big_board)));
+        }
+    }
+
+    const SAMPLE: &str = include_str!("../sample.txt");
+}
@@ -2,27 +2,60 @@
 
 // No allocation! ... WEEE !
 
-fn main() {
-    let input = include_str!("input.txt");
-    println!("Part1 : {}", Reader::new(input).version_sum());
-    println!("Part2 : {}", Reader::new(input).eval());
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Transmission {
+    text: String,
+}
+
+impl Solution for Transmission {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        let text = input.trim();
+        if let Some((offset, c)) = text.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            let part = &text[offset..offset + c.len_utf8()];
+            return Err(ParseError::at(0, text, part, "hexadecimal digit expected"));
+        }
+        let mut reader = Reader::new(text);
+        walk(
+            &mut reader,
+            &ProcessPacketFn(process_packet_for_version_sum),
+        );
+        if reader.truncated {
+            let column = text.len() + 1;
+            return Err(ParseError::new(1, column, "", "packet cut short"));
+        }
+        Ok(Transmission {
+            text: text.to_owned(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        Reader::new(&self.text).version_sum().into()
+    }
+
+    fn part2(&self) -> Answer {
+        Reader::new(&self.text).eval().into()
+    }
 }
 
 #[derive(Clone, Copy)]
-struct Reader<'a> {
+pub struct Reader<'a> {
     text: &'a str,
     bit_index: usize,
     hex_index: usize,
     hex: Option<u8>,
+    /// Set when a packet reads past the end of the text; missing bits read as 0.
+    truncated: bool,
 }
 
 impl<'a> Reader<'a> {
-    fn new(text: &'a str) -> Self {
+    pub fn new(text: &'a str) -> Self {
         Self {
             text,
             bit_index: 0,
             hex: None,
             hex_index: usize::MAX,
+            truncated: false,
         }
     }
 
@@ -41,7 +74,10 @@ impl<'a> Reader<'a> {
                 .map(|c| c as u8);
             self.hex
         }
-        .unwrap()
+        .unwrap_or_else(|| {
+            self.truncated = true;
+            0
+        })
     }
 
     fn next_bits(&mut self, bit_count: u8) -> u64 {
@@ -58,11 +94,11 @@ impl<'a> Reader<'a> {
         result
     }
 
-    fn version_sum(mut self) -> u64 {
+    pub fn version_sum(mut self) -> u64 {
         walk(&mut self, &ProcessPacketFn(process_packet_for_version_sum))
     }
 
-    fn eval(mut self) -> u64 {
+    pub fn eval(mut self) -> u64 {
         walk(&mut self, &ProcessPacketFn(process_packet_for_eval))
     }
 }
@@ -208,6 +244,7 @@ impl SubPackets {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::{collection::vec, prelude::*};
 
     #[test]
     fn test_hex_at() {
@@ -244,10 +281,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error() {
+        assert!(Transmission::parse("8A004A801A8002F478\n").is_ok());
+        let error = Transmission::parse("8A004G").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Transmission::parse("8A004A801A").err().unwrap();
+        assert_eq!(error.column, 11);
+    }
+
     #[test]
     fn test_eval() {
         assert_eq!(Reader::new("C200B40A82").eval(), 3);
         assert_eq!(Reader::new("04005AC33890").eval(), 54);
         assert_eq!(Reader::new("9C0141080250320F1802104A08").eval(), 1);
     }
+
+    #[derive(Debug, Clone)]
+    enum Packet {
+        Literal(u64, u64),
+        Operator(u64, u64, bool, Vec<Packet>),
+    }
+
+    impl Packet {
+        fn version_sum(&self) -> u64 {
+            match self {
+                Packet::Literal(version, _) => *version,
+                Packet::Operator(version, _, _, children) => {
+                    version + children.iter().map(Packet::version_sum).sum::<u64>()
+                }
+            }
+        }
+
+        fn eval(&self) -> u64 {
+            match self {
+                Packet::Literal(_, value) => *value,
+                Packet::Operator(_, type_id, _, children) => {
+                    let mut values = children.iter().map(Packet::eval);
+                    match type_id {
+                        0 => values.fold(0, u64::wrapping_add),
+                        1 => values.fold(1, u64::wrapping_mul),
+                        2 => values.min().unwrap(),
+                        3 => values.max().unwrap(),
+                        _ => {
+                            let (a, b) = (values.next().unwrap(), values.next().unwrap());
+                            u64::from([a > b, a < b, a == b][*type_id as usize - 5])
+                        }
+                    }
+                }
+            }
+        }
+
+        fn push_bits(bits: &mut Vec<bool>, value: u64, count: u8) {
+            bits.extend((0..count).rev().map(|bit| value >> bit & 1 == 1));
+        }
+
+        fn encode(&self, bits: &mut Vec<bool>) {
+            match self {
+                Packet::Literal(version, value) => {
+                    Self::push_bits(bits, *version, 3);
+                    Self::push_bits(bits, 4, 3);
+                    let groups = (1..16).find(|g| value >> (4 * g) == 0).unwrap_or(16);
+                    for group in (0..groups).rev() {
+                        Self::push_bits(bits, (group > 0) as u64, 1);
+                        Self::push_bits(bits, value >> (4 * group) & 0xf, 4);
+                    }
+                }
+                Packet::Operator(version, type_id, by_count, children) => {
+                    Self::push_bits(bits, *version, 3);
+                    Self::push_bits(bits, *type_id, 3);
+                    let mut children_bits = vec![];
+                    children.iter().for_each(|child| child.encode(&mut children_bits));
+                    if *by_count {
+                        Self::push_bits(bits, 1, 1);
+                        Self::push_bits(bits, children.len() as u64, 11);
+                    } else {
+                        Self::push_bits(bits, 0, 1);
+                        Self::push_bits(bits, children_bits.len() as u64, 15);
+                    }
+                    bits.extend(children_bits);
+                }
+            }
+        }
+
+        fn to_hex(&self) -> String {
+            let mut bits = vec![];
+            self.encode(&mut bits);
+            bits.resize(bits.len().div_ceil(8) * 8, false);
+            let nibble = |chunk: &[bool]| chunk.iter().fold(0, |n, &bit| n << 1 | bit as u32);
+            bits.chunks(4)
+                .map(|chunk| char::from_digit(nibble(chunk), 16).unwrap().to_ascii_uppercase())
+                .collect()
+        }
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let literal = (0..8u64, any::<u64>()).prop_map(|(v, value)| Packet::Literal(v, value));
+        literal.prop_recursive(4, 40, 4, |inner| {
+            (0..8u64, 0..7u64, any::<bool>(), vec(inner, 1..4)).prop_map(
+                |(version, type_id, by_count, mut children)| {
+                    let type_id = if type_id < 4 { type_id } else { type_id + 1 };
+                    if type_id > 4 {
+                        children.resize(2, Packet::Literal(0, 0));
+                    }
+                    Packet::Operator(version, type_id, by_count, children)
+                },
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "[0-9A-F]{0,64}|\\PC*") {
+            if let Ok(transmission) = Transmission::from_input(&text) {
+                transmission.part1();
+                transmission.part2();
+            }
+        }
+
+        #[test]
+        fn reader_stays_in_text(text in "[0-9A-F]{0,64}") {
+            let mut reader = Reader::new(&text);
+            walk(&mut reader, &ProcessPacketFn(process_packet_for_version_sum));
+            prop_assert_eq!(reader.truncated, reader.bit_index > text.len() * 4);
+        }
+
+        #[test]
+        fn decodes_encoded_packets(packet in packet()) {
+            let hex = packet.to_hex();
+            prop_assert!(Transmission::parse(&hex).is_ok());
+            prop_assert_eq!(Reader::new(&hex).version_sum(), packet.version_sum());
+            prop_assert_eq!(Reader::new(&hex).eval(), packet.eval());
+        }
+    }
 }
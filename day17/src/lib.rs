@@ -0,0 +1,279 @@
+// #![allow(dead_code)]
+
+use aoc_core::{expect_part, parse_at, Answer, ParseError, Solution};
+
+impl Solution for Target {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Target::from_string(input)
+    }
+
+    fn part1(&self) -> Answer {
+        find_highest_y(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        solution_count(self).into()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Pos {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Target {
+    x_range: (i32, i32),
+    y_range: (i32, i32),
+}
+
+impl Target {
+    /// Parses `target area: x=20..30, y=-10..-5`.
+    pub fn from_string(text: &str) -> aoc_core::Result<Target> {
+        let line = text.trim();
+        let mut tokens = line.split(' ').skip(2);
+        let mut range = |axis: &str| -> aoc_core::Result<(i32, i32)> {
+            let token = expect_part(0, line, tokens.next(), &format!("{} range", axis))?;
+            let mut parts = token.trim_end_matches(',').split('=');
+            let name = parts.next().unwrap_or_default();
+            if name != axis {
+                let message = format!("{}= expected", axis);
+                return Err(ParseError::at(0, line, name, message));
+            }
+            let bounds = expect_part(0, line, parts.next(), "=")?;
+            let mut bounds = bounds.split("..");
+            let low = bounds.next().unwrap_or_default();
+            let high = expect_part(0, line, bounds.next(), "..")?;
+            let (low_value, high_value) = (parse_at(0, line, low)?, parse_at(0, line, high)?);
+            if low_value > high_value {
+                return Err(ParseError::at(0, line, high, "upper bound expected"));
+            }
+            Ok((low_value, high_value))
+        };
+        let target = Target {
+            x_range: range("x")?,
+            y_range: range("y")?,
+        };
+        if target.x_range.0 <= 0 || target.y_range.1 >= 0 {
+            let message = "target should be right of and below the launcher";
+            return Err(ParseError::line(0, line, message));
+        }
+        Ok(target)
+    }
+    #[inline(always)]
+    fn in_y_range(&self, y: i32) -> bool {
+        y >= self.y_range.0 && y <= self.y_range.1
+    }
+    #[inline(always)]
+    fn in_x_range(&self, x: i32) -> bool {
+        x >= self.x_range.0 && x <= self.x_range.1
+    }
+}
+
+fn max_dy(target: &Target) -> i32 {
+    // - For any positive dy we eventually get back to y = 0.
+    // - At that point our y speed would -dy.
+    // - The next step will take us to: -dy - 1
+    // - The biggest step we can take at that moment is one that will
+    //   reach the bottom of the Target.
+    // - That step will have: bottom_y = -dy - 1
+    // - So we can solve for dy:
+    //    -dy = bottom_y + 1
+    //     dy = -(bottom_y + 1) // abstracted in max_dy
+    // - In our example that value would be -(-10 + 1) = 9
+    -(target.y_range.0 + 1)
+}
+
+pub fn find_highest_y(target: &Target) -> i64 {
+    // - max = sum(range(max_dy))
+    // - in our example that's 9 + 8 + 7 + 6 + 5 + 4 + 3 + 2 + 1 = 45
+    // - That's an arithmetic serie so the result is  (n + 1) * (v(n) + v(1)) / 2
+    // - max_dy only works when some dx stops right above the target, if none does
+    //   we try lower shots until one hits. (max_dx, min_dy) always does, in one step.
+    let n = (min_dy(target)..=max_dy(target))
+        .rev()
+        .find(|&dy| (min_dx(target)..=max_dx(target)).any(|dx| simulate(target, dx, dy)))
+        .map_or(0, |dy| dy.max(0) as i64);
+    (n + 1) * n / 2 // better than my first idea : (1..=max_dy(target)).sum()
+}
+
+fn min_dy(target: &Target) -> i32 {
+    // Like in max_dy, we need to reach lowest point in a single step
+    target.y_range.0
+}
+
+fn min_dx(target: &Target) -> i32 {
+    //   |
+    // v |\
+    //   | \
+    //   |  \
+    //   | e \
+    //   +------
+    //        t
+    // e = vt/2
+    // In ou problem v = t because for each step we lose one speed
+    // e = v²/2
+    // v = sqrt(2e)
+    // Looks like truncated is good enough for discrete movement ?
+    (target.x_range.0 as f32 * 2.0).sqrt() as i32
+}
+
+fn max_dx(target: &Target) -> i32 {
+    target.x_range.1
+}
+
+fn simulate(target: &Target, mut dx: i32, mut dy: i32) -> bool {
+    let mut pos_x = 0;
+    let mut pos_y = 0;
+    while pos_x <= target.x_range.1 && pos_y >= target.y_range.0 {
+        pos_x += dx;
+        pos_y += dy;
+        if target.in_x_range(pos_x) && target.in_y_range(pos_y) {
+            return true;
+        }
+        dx = (dx - 1).max(0);
+        dy -= 1;
+    }
+    false
+}
+
+pub fn solution_count(target: &Target) -> i32 {
+    let mut result = 0;
+    for dx in min_dx(target)..=max_dx(target) {
+        for dy in min_dy(target)..=max_dy(target) {
+            if simulate(target, dx, dy) {
+                result += 1;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_string() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(
+            target,
+            Target {
+                x_range: (20, 30),
+                y_range: (-10, -5),
+            }
+        );
+        let error = Target::from_string("target area: x=20..3O, y=-10..-5").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (20, "3O"));
+        let error = Target::from_string("target area: y=20..30, x=-10..-5").unwrap_err();
+        assert_eq!(error.column, 14);
+        assert!(Target::from_string("target area: x=20..30,").is_err());
+        assert!(Target::from_string("target area: x=20, y=-10..-5").is_err());
+        let error = Target::from_string("target area: x=30..20, y=-10..-5").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (20, "20"));
+        assert!(Target::from_string("target area: x=20..30, y=5..10").is_err());
+        assert!(Target::from_string("target area: x=-30..-20, y=-10..-5").is_err());
+    }
+
+    #[test]
+    fn test_find_highest_y() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(find_highest_y(&target), 45);
+        // No dx stops above x=46, so the probe cannot drop straight down into it.
+        let target = Target::from_string("target area: x=46..46, y=-3..-2").unwrap();
+        assert_eq!(find_highest_y(&target), 1);
+    }
+
+    #[test]
+    fn test_min_dx() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(min_dx(&target), 6);
+    }
+
+    #[test]
+    fn test_max_dx() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(max_dx(&target), 30);
+    }
+
+    #[test]
+    fn test_min_dy() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(min_dy(&target), -10);
+    }
+
+    #[test]
+    fn test_max_dy() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(max_dy(&target), 9);
+    }
+
+    #[test]
+    fn test_part2() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert_eq!(solution_count(&target), 112);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert!(simulate(&target, 30, -7));
+        assert!(simulate(&target, 9, -2));
+        assert!(simulate(&target, 25, -9));
+    }
+
+    #[test]
+    fn test_in_x_range() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert!(target.in_x_range(20));
+        assert!(target.in_x_range(30));
+        assert!(!target.in_x_range(19));
+        assert!(!target.in_x_range(31));
+    }
+    #[test]
+    fn test_in_y_range() {
+        let target = Target::from_string(SAMPLE).unwrap();
+        assert!(target.in_y_range(-10));
+        assert!(target.in_y_range(-5));
+        assert!(!target.in_y_range(-11));
+        assert!(!target.in_y_range(-4));
+    }
+    fn target() -> impl Strategy<Value = Target> {
+        (1..60, 0..20, -60..0, 0..20).prop_map(|(x, width, y, height)| Target {
+            x_range: (x, x + width),
+            y_range: (y - height, y),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*") {
+            if let Ok(target) = Target::from_input(&text) {
+                target.part1();
+                target.part2();
+            }
+        }
+
+        /// Tries every velocity that could reach the target, without the search bounds.
+        #[test]
+        fn matches_brute_force(target in target()) {
+            let mut count = 0;
+            let mut highest = 0;
+            for dx in 0..=target.x_range.1 {
+                for dy in target.y_range.0..=-target.y_range.0 {
+                    if simulate(&target, dx, dy) {
+                        count += 1;
+                        let dy = dy.max(0) as i64;
+                        highest = highest.max(dy * (dy + 1) / 2);
+                    }
+                }
+            }
+            prop_assert_eq!(solution_count(&target), count);
+            prop_assert_eq!(find_highest_y(&target), highest);
+        }
+    }
+
+    const SAMPLE: &str = "target area: x=20..30, y=-10..-5";
+}
@@ -0,0 +1,429 @@
+//#![allow(dead_code)]
+
+use aoc_core::{Answer, ParseError, Solution};
+
+impl Solution for Homework {
+    fn parse(input: &str) -> aoc_core::Result<Self> {
+        Homework::parse(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.sum().magnitude().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.largest_magnitude_sum().into()
+    }
+}
+
+pub struct Homework {
+    nums: Vec<SNum>,
+}
+
+impl Homework {
+    pub fn parse(text: &str) -> aoc_core::Result<Self> {
+        let nums = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let snum = SNum::parse(index, line)?;
+                if snum.values.iter().any(|value| value.depth > 4) {
+                    let message = "homework numbers should be reduced";
+                    return Err(ParseError::line(index, line, message));
+                }
+                Ok(snum)
+            })
+            .collect::<aoc_core::Result<Vec<SNum>>>()?;
+        if nums.is_empty() {
+            return Err(ParseError::new(1, 1, "", "snailfish number expected"));
+        }
+        Ok(Homework { nums })
+    }
+
+    pub fn sum(&self) -> SNum {
+        let mut result = self.nums.first().unwrap().clone();
+        for other in self.nums[1..].iter() {
+            result = result.reduced_sum(other);
+        }
+        result
+    }
+
+    pub fn largest_magnitude_sum(&self) -> u64 {
+        let mut largest = 0;
+        for (i, snum) in self.nums.iter().enumerate() {
+            for (j, other) in self.nums.iter().enumerate() {
+                if i != j {
+                    largest = largest.max(snum.reduced_sum(other).magnitude());
+                }
+            }
+        }
+        largest
+    }
+}
+
+#[derive(Clone)]
+pub struct SNum {
+    values: Vec<Value>,
+}
+
+impl SNum {
+    /// Parses `text`, line `index` of the homework. Regular numbers are single digits and pairs
+    /// at most five deep, the deepest a number gets before exploding.
+    fn parse(index: usize, text: &str) -> aoc_core::Result<Self> {
+        // How much of each open pair has been read: 0 nothing, 1 left, 2 comma, 3 right.
+        let mut pairs: Vec<u8> = vec![];
+        let mut values = vec![];
+        let mut complete = false;
+        let line = text.trim();
+        for (offset, c) in line.char_indices() {
+            let error = |message| {
+                let part = &line[offset..offset + c.len_utf8()];
+                ParseError::at(index, text, part, message)
+            };
+            let expected = match pairs.last() {
+                None if complete => "end of line expected",
+                None => "[ expected",
+                Some(1) => ", expected",
+                Some(3) => "] expected",
+                Some(_) => "[ or digit expected",
+            };
+            let element = match c {
+                '[' if complete || pairs.last() == Some(&1) || pairs.last() == Some(&3) => {
+                    return Err(error(expected))
+                }
+                '[' if pairs.len() == 5 => return Err(error("nested too deep")),
+                '[' => {
+                    pairs.push(0);
+                    false
+                }
+                ',' if pairs.last() == Some(&1) => {
+                    *pairs.last_mut().unwrap() = 2;
+                    false
+                }
+                ']' if pairs.last() == Some(&3) => {
+                    pairs.pop();
+                    true
+                }
+                c if matches!(pairs.last(), Some(0) | Some(2)) => {
+                    let num = c.to_digit(10).ok_or_else(|| error(expected))?;
+                    values.push(Value {
+                        num: num.into(),
+                        depth: pairs.len() as u8,
+                    });
+                    true
+                }
+                _ => return Err(error(expected)),
+            };
+            if element {
+                match pairs.last_mut() {
+                    Some(read) => *read += 1,
+                    None => complete = true,
+                }
+            }
+        }
+        if !complete {
+            let column = text.chars().count() + 1;
+            let message = "incomplete snailfish number";
+            return Err(ParseError::new(index + 1, column, text, message));
+        }
+        Ok(SNum { values })
+    }
+
+    fn sum(&self, other: &Self) -> Self {
+        SNum {
+            values: self
+                .values
+                .iter()
+                .copied()
+                .map(Value::inc_depth)
+                .chain(other.values.iter().copied().map(Value::inc_depth))
+                .collect(),
+        }
+    }
+
+    fn explode(&mut self) -> bool {
+        if let Some((explode_at, _)) = self
+            .values
+            .iter()
+            .enumerate()
+            .find(|(_, val)| val.depth > 4)
+        {
+            let left = self.values[explode_at];
+            let right = self.values[explode_at + 1];
+            if explode_at > 0 {
+                self.values[explode_at - 1].num += left.num;
+            }
+            let next_index = explode_at + 2;
+            if next_index < self.values.len() {
+                self.values[next_index].num += right.num;
+            }
+            self.values[explode_at] = Value {
+                num: 0,
+                depth: left.depth - 1,
+            };
+            self.values.remove(explode_at + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn split(&mut self) -> bool {
+        if let Some((split_at, value)) = self.values.iter().enumerate().find(|(_, val)| val.num > 9)
+        {
+            let left = Value {
+                depth: value.depth + 1,
+                num: value.num / 2,
+            };
+            let right = Value {
+                depth: value.depth + 1,
+                num: value.num.div_ceil(2),
+            };
+            self.values[split_at] = left;
+            self.values.insert(split_at + 1, right);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn reduce(mut self) -> Self {
+        while self.explode() || self.split() {}
+        self
+    }
+
+    fn reduced_sum(&self, other: &Self) -> Self {
+        self.sum(other).reduce()
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        fn recurse(vals: &[Value], depth: u8) -> (u64, &[Value]) {
+            if let Some((val, rest)) = vals.split_first() {
+                if depth < val.depth {
+                    let (left, rest) = recurse(vals, depth + 1);
+                    let (right, rest) = recurse(rest, depth + 1);
+                    (3 * left + 2 * right, rest)
+                } else {
+                    (val.num, rest)
+                }
+            } else {
+                unreachable!()
+            }
+        }
+        recurse(&self.values, 0).0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Value {
+    num: u64,
+    depth: u8,
+}
+
+impl Value {
+    fn inc_depth(mut self) -> Self {
+        self.depth += 1;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    fn parse_snum(text: &str) -> SNum {
+        SNum::parse(0, text).unwrap()
+    }
+
+    impl SNum {
+        fn as_string(&self) -> String {
+            fn recurse<'a>(
+                vals: &'a [Value],
+                depth: u8,
+                string: &mut String,
+            ) -> &'a [Value] {
+                if let Some((val, rest)) = vals.split_first() {
+                    if depth < val.depth {
+                        string.push('[');
+                        let result = recurse(vals, depth + 1, string);
+                        string.push(',');
+                        let result = recurse(result, depth + 1, string);
+                        string.push(']');
+                        result
+                    } else {
+                        string.push_str(&format!("{}", val.num));
+                        rest
+                    }
+                } else {
+                    unreachable!()
+                }
+            }
+            let mut result = String::with_capacity(100);
+            recurse(&self.values, 0, &mut result);
+            result
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = SNum::parse(4, "[1,[2,x]]").err().unwrap();
+        assert_eq!((error.line, error.column), (5, 7));
+        let error = SNum::parse(0, "[1,2]]").err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (6, "]"));
+        assert!(SNum::parse(0, "[[1,2]").is_err());
+        assert!(Homework::parse("\n").is_err());
+        let error = SNum::parse(0, "[1,2,3]").err().unwrap();
+        assert_eq!((error.column, error.message.as_str()), (5, "] expected"));
+        let error = SNum::parse(0, "[[1]]").err().unwrap();
+        assert_eq!((error.column, error.message.as_str()), (4, ", expected"));
+        assert!(SNum::parse(0, "1").is_err());
+        assert!(SNum::parse(0, "[1,2][3,4]").is_err());
+        assert!(SNum::parse(0, "[[[[[[1,2],3],4],5],6],7]").is_err());
+        let error = Homework::parse("[1,2]\n[[[[[1,2],3],4],5],6]").err().unwrap();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_snum("[1,2]").as_string(), "[1,2]");
+        assert_eq!(
+            parse_snum("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]")
+                .as_string(),
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]"
+        );
+    }
+    #[test]
+    fn test_sum() {
+        assert_eq!(
+            parse_snum("[1,2]")
+                .sum(&parse_snum("[[3,4],5]"))
+                .as_string(),
+            "[[1,2],[[3,4],5]]"
+        );
+    }
+
+    #[test]
+    fn test_identity_reduction() {
+        assert!(
+            !parse_snum("[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]").explode()
+        );
+    }
+
+    #[test]
+    fn test_explode() {
+        let mut snum = parse_snum("[[[[[9,8],1],2],3],4]");
+        assert!(snum.explode());
+        assert_eq!(
+            snum.as_string(),
+            parse_snum("[[[[0,9],2],3],4]").as_string()
+        );
+        let mut snum = parse_snum("[7,[6,[5,[4,[3,2]]]]]");
+        assert!(snum.explode());
+        assert_eq!(
+            snum.as_string(),
+            parse_snum("[7,[6,[5,[7,0]]]]").as_string()
+        );
+        let mut snum = parse_snum("[[6,[5,[4,[3,2]]]],1]");
+        assert!(snum.explode());
+        assert_eq!(
+            snum.as_string(),
+            parse_snum("[[6,[5,[7,0]]],3]").as_string()
+        );
+        let mut snum = parse_snum("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]");
+        assert!(snum.explode());
+        assert_eq!(
+            snum.as_string(),
+            parse_snum("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").as_string()
+        );
+        let mut snum = parse_snum("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
+        assert!(snum.explode());
+        assert_eq!(
+            snum.as_string(),
+            parse_snum("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").as_string()
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let mut snum = parse_snum("[1,1]");
+        snum.values[1].num = 10;
+        assert!(snum.split());
+        assert_eq!(snum.as_string(), parse_snum("[1,[5,5]]").as_string());
+        let mut snum = parse_snum("[1,1]");
+        snum.values[0].num = 11;
+        assert!(snum.split());
+        assert_eq!(snum.as_string(), parse_snum("[[5,6],1]").as_string());
+    }
+
+    #[test]
+    fn test_reduce() {
+        assert_eq!(
+            parse_snum("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]")
+                .reduce()
+                .as_string(),
+            parse_snum("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").as_string()
+        )
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(parse_snum("[9,1]").magnitude(), 29);
+        assert_eq!(parse_snum("[[9,1],[1,9]]").magnitude(), 129);
+    }
+
+    #[test]
+    fn test_example() {
+        let homework = Homework::parse(EXAMPLE).unwrap();
+        assert_eq!(homework.sum().magnitude(), 4140);
+    }
+
+    #[test]
+    fn test_largest_magnitude() {
+        let homework = Homework::parse(EXAMPLE).unwrap();
+        assert_eq!(homework.largest_magnitude_sum(), 3993);
+    }
+
+    /// A reduced snailfish number, as found in the homework.
+    fn snum() -> impl Strategy<Value = String> {
+        let leaf = (0..10u8).prop_map(|num| num.to_string());
+        let pair = leaf.prop_recursive(3, 16, 2, |inner| {
+            (inner.clone(), inner).prop_map(|(left, right)| format!("[{},{}]", left, right))
+        });
+        (pair.clone(), pair).prop_map(|(left, right)| format!("[{},{}]", left, right))
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(text in "\\PC*|[\\[\\],0-9]{0,32}") {
+            if let Ok(homework) = Homework::from_input(&text) {
+                homework.part1();
+                homework.part2();
+            }
+        }
+
+        #[test]
+        fn parse_round_trip(text in snum()) {
+            prop_assert_eq!(parse_snum(&text).as_string(), text);
+        }
+
+        /// Reducing leaves no pair too deep and no regular number too large, and a sum
+        /// before reduction weighs its terms 3 and 2.
+        #[test]
+        fn sums_are_reduced(texts in vec(snum(), 2..6)) {
+            let snums: Vec<SNum> = texts.iter().map(|text| parse_snum(text)).collect();
+            let sum = snums[0].sum(&snums[1]);
+            prop_assert_eq!(
+                sum.magnitude(),
+                3 * snums[0].magnitude() + 2 * snums[1].magnitude()
+            );
+            let homework = Homework::parse(&texts.join("\n")).unwrap();
+            let reduced = homework.sum();
+            prop_assert!(reduced.values.iter().all(|value| value.depth <= 4 && value.num <= 9));
+            prop_assert_eq!(parse_snum(&reduced.as_string()).as_string(), reduced.as_string());
+        }
+    }
+
+    const EXAMPLE: &str = include_str!("../sample.txt");
+}